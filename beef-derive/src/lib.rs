@@ -0,0 +1,277 @@
+//! Derive macros for [`beef`](https://docs.rs/beef/).
+//!
+//! Use them through the `derive` feature of `beef` rather than depending on this crate directly.
+//!
+//! ```rust
+//! use beef::{Cow, IntoStatic};
+//!
+//! #[derive(IntoStatic)]
+//! struct Token<'a> {
+//!     text: Cow<'a, str>,
+//!     line: usize,
+//! }
+//!
+//! let source = String::from("foo");
+//! let token = Token {
+//!     text: Cow::borrowed(&source),
+//!     line: 1,
+//! };
+//! let token: Token<'static> = token.into_static();
+//!
+//! drop(source);
+//!
+//! assert_eq!(token.text, "foo");
+//! ```
+#![warn(missing_docs)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::visit::Visit;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, GenericArgument, GenericParam, Lifetime,
+    PathArguments, Type,
+};
+
+/// Derives `beef::IntoStatic` for a struct or an enum.
+///
+/// All lifetime parameters are replaced by `'static` in the resulting type. Fields whose
+/// types mention any of those lifetimes are converted with `IntoStatic::into_static`,
+/// all other fields are moved as they are.
+///
+/// A field of type `Cow<'a, [T]>` where `T` itself borrows, such as `Cow<'a, [Cow<'a, str>]>`,
+/// is converted into an owned `Cow` with every element converted using `IntoStatic`.
+/// Such a `Cow` nested inside another type can't be converted:
+///
+/// ```compile_fail
+/// use beef::{Cow, IntoStatic};
+///
+/// #[derive(IntoStatic)]
+/// struct List<'a> {
+///     items: Option<Cow<'a, [Cow<'a, str>]>>,
+/// }
+/// ```
+#[proc_macro_derive(IntoStatic)]
+pub fn derive_into_static(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match into_static(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn into_static(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let lifetimes: Vec<Lifetime> = input
+        .generics
+        .lifetimes()
+        .map(|param| param.lifetime.clone())
+        .collect();
+
+    let static_params = input.generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(_) => quote!('static),
+        GenericParam::Type(param) => {
+            let ident = &param.ident;
+            quote!(#ident)
+        }
+        GenericParam::Const(param) => {
+            let ident = &param.ident;
+            quote!(#ident)
+        }
+    });
+
+    let mut generics = input.generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+
+        for param in input.generics.type_params() {
+            let ident = &param.ident;
+
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ident: 'static));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, construct) = convert_fields(&data.fields, &lifetimes)?;
+
+            quote! {
+                let #name #pattern = self;
+
+                #name #construct
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let (pattern, construct) = convert_fields(&variant.fields, &lifetimes)?;
+
+                    Ok(quote! {
+                        #name::#ident #pattern => #name::#ident #construct,
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "IntoStatic can not be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::beef::IntoStatic for #name #ty_generics #where_clause {
+            type Static = #name<#(#static_params),*>;
+
+            #[inline]
+            fn into_static(self) -> Self::Static {
+                #body
+            }
+        }
+    })
+}
+
+/// Produces a pattern destructuring `fields`, and the matching tokens
+/// that construct the `'static` counterpart out of the bindings.
+fn convert_fields(
+    fields: &Fields,
+    lifetimes: &[Lifetime],
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let bindings: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field{}", index),
+        })
+        .collect();
+
+    let values = fields
+        .iter()
+        .zip(&bindings)
+        .map(|(field, binding)| {
+            let element = borrowed_cow_slice(&field.ty, lifetimes);
+
+            if contains_borrowed_cow_slice(element.unwrap_or(&field.ty), lifetimes) {
+                return Err(Error::new_spanned(
+                    &field.ty,
+                    format!(
+                        "IntoStatic can't convert field `{}`: a `Cow<[T]>` with borrowed \
+                         elements is only supported as the type of the field itself",
+                        binding,
+                    ),
+                ));
+            }
+
+            Ok(if element.is_some() {
+                quote! {
+                    ::beef::generic::Cow::owned(::beef::IntoStatic::into_static(
+                        ::beef::generic::Cow::into_owned(#binding),
+                    ))
+                }
+            } else if borrows(&field.ty, lifetimes) {
+                quote!(::beef::IntoStatic::into_static(#binding))
+            } else {
+                quote!(#binding)
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(match fields {
+        Fields::Named(_) => (
+            quote!({ #(#bindings),* }),
+            quote!({ #(#bindings: #values),* }),
+        ),
+        Fields::Unnamed(_) => (quote!(( #(#bindings),* )), quote!(( #(#values),* ))),
+        Fields::Unit => (TokenStream2::new(), TokenStream2::new()),
+    })
+}
+
+/// Returns the element type of `ty` if it is a `Cow<[T]>`, and `T` mentions
+/// any of the `lifetimes`.
+fn borrowed_cow_slice<'t>(ty: &'t Type, lifetimes: &[Lifetime]) -> Option<&'t Type> {
+    let segment = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != "Cow" {
+        return None;
+    }
+
+    let args = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => &args.args,
+        _ => return None,
+    };
+
+    args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(Type::Slice(slice)) if borrows(&slice.elem, lifetimes) => {
+            Some(&*slice.elem)
+        }
+        _ => None,
+    })
+}
+
+/// Checks whether `ty` is, or contains, a `Cow<[T]>` with borrowed elements.
+fn contains_borrowed_cow_slice(ty: &Type, lifetimes: &[Lifetime]) -> bool {
+    struct Visitor<'l> {
+        lifetimes: &'l [Lifetime],
+        found: bool,
+    }
+
+    impl<'ast> Visit<'ast> for Visitor<'_> {
+        fn visit_type(&mut self, ty: &'ast Type) {
+            if borrowed_cow_slice(ty, self.lifetimes).is_some() {
+                self.found = true;
+            } else {
+                syn::visit::visit_type(self, ty);
+            }
+        }
+    }
+
+    let mut visitor = Visitor {
+        lifetimes,
+        found: false,
+    };
+
+    visitor.visit_type(ty);
+    visitor.found
+}
+
+/// Checks whether `ty` mentions any of the `lifetimes`.
+fn borrows(ty: &Type, lifetimes: &[Lifetime]) -> bool {
+    struct Visitor<'l> {
+        lifetimes: &'l [Lifetime],
+        found: bool,
+    }
+
+    impl<'ast> Visit<'ast> for Visitor<'_> {
+        fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+            self.found |= self.lifetimes.contains(lifetime);
+        }
+    }
+
+    let mut visitor = Visitor {
+        lifetimes,
+        found: false,
+    };
+
+    visitor.visit_type(ty);
+    visitor.found
+}
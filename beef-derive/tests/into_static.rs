@@ -0,0 +1,126 @@
+use beef::{Cow, IntoStatic};
+
+#[derive(IntoStatic, Debug, PartialEq)]
+struct Named<'a> {
+    name: Cow<'a, str>,
+    tags: Vec<Cow<'a, str>>,
+    alias: Option<Cow<'a, str>>,
+    bytes: beef::lean::Cow<'a, [u8]>,
+    line: usize,
+}
+
+#[derive(IntoStatic, Debug, PartialEq)]
+struct Nested<'a> {
+    items: Cow<'a, [Cow<'a, str>]>,
+    lean: beef::lean::Cow<'a, [Option<Cow<'a, str>>]>,
+}
+
+#[derive(IntoStatic, Debug, PartialEq)]
+struct Unnamed<'a>(Cow<'a, str>, u32);
+
+#[derive(IntoStatic, Debug, PartialEq)]
+struct Unit;
+
+#[derive(IntoStatic, Debug, PartialEq)]
+enum Expr<'a, T: Clone> {
+    Ident(Cow<'a, str>),
+    Call {
+        callee: Box<Expr<'a, T>>,
+        args: Vec<Expr<'a, T>>,
+    },
+    Value(T),
+    Empty,
+}
+
+fn detach<T: IntoStatic>(value: T) -> T::Static {
+    value.into_static()
+}
+
+#[test]
+fn named_struct() {
+    let source = String::from("foo bar baz");
+    let named = Named {
+        name: Cow::borrowed(&source[..3]),
+        tags: vec![Cow::borrowed(&source[4..7]), Cow::owned("qux".into())],
+        alias: Some(Cow::borrowed(&source[8..])),
+        bytes: beef::lean::Cow::borrowed(source.as_bytes()),
+        line: 42,
+    };
+
+    let named: Named<'static> = detach(named);
+
+    drop(source);
+
+    assert_eq!(named.name, "foo");
+    assert_eq!(named.tags, ["bar", "qux"]);
+    assert_eq!(named.alias.as_ref().unwrap(), "baz");
+    assert_eq!(named.bytes, &b"foo bar baz"[..]);
+    assert_eq!(named.line, 42);
+
+    assert!(named.name.is_owned());
+    assert!(named.tags.iter().all(Cow::is_owned));
+}
+
+#[test]
+fn nested_cow() {
+    let source = String::from("foo bar");
+    let items = [Cow::borrowed(&source[..3]), Cow::borrowed(&source[4..])];
+    let lean = [Some(Cow::borrowed(&source[..3])), None];
+    let nested = Nested {
+        items: Cow::borrowed(&items[..]),
+        lean: beef::lean::Cow::borrowed(&lean[..]),
+    };
+
+    let nested: Nested<'static> = detach(nested);
+
+    drop(items);
+    drop(lean);
+    drop(source);
+
+    assert_eq!(&nested.items[..], ["foo", "bar"]);
+    assert_eq!(&nested.lean[..], [Some(Cow::borrowed("foo")), None]);
+
+    assert!(nested.items.is_owned());
+    assert!(nested.items.iter().all(Cow::is_owned));
+    assert!(nested.lean.is_owned());
+}
+
+#[test]
+fn unnamed_and_unit_struct() {
+    let source = String::from("foo");
+    let unnamed: Unnamed<'static> = detach(Unnamed(Cow::borrowed(&source), 7));
+
+    drop(source);
+
+    assert_eq!(unnamed, Unnamed(Cow::borrowed("foo"), 7));
+    assert_eq!(detach(Unit), Unit);
+}
+
+#[test]
+fn recursive_enum() {
+    let source = String::from("print hello");
+    let expr = Expr::Call {
+        callee: Box::new(Expr::Ident(Cow::borrowed(&source[..5]))),
+        args: vec![
+            Expr::Ident(Cow::borrowed(&source[6..])),
+            Expr::Value(1u8),
+            Expr::Empty,
+        ],
+    };
+
+    let expr: Expr<'static, u8> = detach(expr);
+
+    drop(source);
+
+    assert_eq!(
+        expr,
+        Expr::Call {
+            callee: Box::new(Expr::Ident(Cow::borrowed("print"))),
+            args: vec![
+                Expr::Ident(Cow::borrowed("hello")),
+                Expr::Value(1),
+                Expr::Empty,
+            ],
+        }
+    );
+}
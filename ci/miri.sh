@@ -9,4 +9,7 @@ rustup default "$MIRI_NIGHTLY"
 rustup component add miri
 cargo miri setup
 
-MIRIFLAGS='-Zmiri-strict-provenance' cargo miri test --all-features
+# Everything except `proptest`, its strategies leak memory on purpose
+FEATURES=const_fn,derive,impl_arbitrary,impl_bincode,impl_borsh,impl_deepsize,impl_get_size,impl_hashbrown,impl_logos,impl_nom,impl_regex,impl_rkyv,impl_schemars,impl_serde,impl_stable_deref,impl_yoke,impl_zeroize,std
+
+MIRIFLAGS='-Zmiri-strict-provenance' cargo miri test --features "$FEATURES"
@@ -0,0 +1,33 @@
+/*
+ * C definition matching the memory layout of `beef::ffi::FfiCow<str>` and
+ * `beef::ffi::FfiCow<[u8]>`. The layout of `beef::Cow` and `beef::lean::Cow`
+ * themselves is not guaranteed, convert them into an `FfiCow` on the Rust side.
+ *
+ * Values are owned by Rust, C code should only ever read them through a pointer.
+ * Owned data is released by dropping the `FfiCow` on the Rust side.
+ */
+#ifndef BEEF_H
+#define BEEF_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/* `beef::ffi::FfiCow<str>` or `beef::ffi::FfiCow<[u8]>` */
+typedef struct beef_cow {
+    const uint8_t *ptr;
+    /* length in bytes */
+    size_t len;
+    /* 0 if the data is borrowed */
+    size_t capacity;
+} beef_cow;
+
+static inline size_t beef_cow_len(const beef_cow *cow) {
+    return cow->len;
+}
+
+static inline bool beef_cow_is_owned(const beef_cow *cow) {
+    return cow->capacity != 0;
+}
+
+#endif /* BEEF_H */
@@ -0,0 +1,117 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+/// Produces either a `Cow` borrowing from the `Unstructured` buffer,
+/// or an owned one, so that both states get exercised.
+impl<'a, U> Arbitrary<'a> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            <&str>::arbitrary(u).map(Cow::borrowed)
+        } else {
+            String::arbitrary(u).map(Cow::owned)
+        }
+    }
+
+    #[inline]
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            <&str>::arbitrary_take_rest(u).map(Cow::borrowed)
+        } else {
+            String::arbitrary_take_rest(u).map(Cow::owned)
+        }
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(
+            bool::size_hint(depth),
+            size_hint::or(<&str>::size_hint(depth), String::size_hint(depth)),
+        )
+    }
+}
+
+/// Makes the same choice as the `str` impl. Borrowing is only possible
+/// for slices that implement `Arbitrary` themselves, such as `&[u8]`.
+impl<'a, T, U> Arbitrary<'a> for Cow<'a, [T], U>
+where
+    T: Clone,
+    &'a [T]: Arbitrary<'a>,
+    Vec<T>: Arbitrary<'a>,
+    U: Capacity,
+{
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            <&[T]>::arbitrary(u).map(Cow::borrowed)
+        } else {
+            Vec::arbitrary(u).map(Cow::owned)
+        }
+    }
+
+    #[inline]
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            <&[T]>::arbitrary_take_rest(u).map(Cow::borrowed)
+        } else {
+            Vec::arbitrary_take_rest(u).map(Cow::owned)
+        }
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(
+            bool::size_hint(depth),
+            size_hint::or(<&[T]>::size_hint(depth), Vec::<T>::size_hint(depth)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn wide_cow_str() {
+        use crate::Cow;
+
+        let data = b"\x01hello";
+        let cow = Cow::<str>::arbitrary_take_rest(Unstructured::new(data)).unwrap();
+
+        assert_eq!(cow, "hello");
+        assert!(cow.is_borrowed());
+        assert_eq!(cow.as_ptr(), data[1..].as_ptr());
+
+        let cow = Cow::<str>::arbitrary_take_rest(Unstructured::new(b"\x00hello")).unwrap();
+
+        assert_eq!(cow, "hello");
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn lean_cow_bytes() {
+        use crate::lean::Cow;
+
+        let data = b"\x01hello\x05";
+        let cow = Cow::<[u8]>::arbitrary(&mut Unstructured::new(data)).unwrap();
+
+        assert_eq!(cow, &b"hello"[..]);
+        assert!(cow.is_borrowed());
+        assert_eq!(cow.as_ptr(), data[1..].as_ptr());
+
+        let cow =
+            Cow::<[u8]>::arbitrary(&mut Unstructured::new(b"\x00\x01h\x01e\x01l\x01l\x01o\x00"))
+                .unwrap();
+
+        assert_eq!(cow, &b"hello"[..]);
+        assert!(cow.is_owned());
+    }
+}
@@ -0,0 +1,127 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use bincode::de::{BorrowDecode, BorrowDecoder, Decode, Decoder};
+use bincode::enc::{Encode, Encoder};
+use bincode::error::{DecodeError, EncodeError};
+
+use crate::generic::Cow;
+use crate::traits::{Beef, Capacity};
+
+impl<T, U> Encode for Cow<'_, T, U>
+where
+    T: Beef + Encode + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        T::encode(self.as_ref(), encoder)
+    }
+}
+
+impl<Context, U> Decode<Context> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        String::decode(decoder).map(Cow::owned)
+    }
+}
+
+impl<'de: 'a, 'a, Context, U> BorrowDecode<'de, Context> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        <&str>::borrow_decode(decoder).map(Cow::borrowed)
+    }
+}
+
+impl<Context, U> Decode<Context> for Cow<'_, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Vec::decode(decoder).map(Cow::owned)
+    }
+}
+
+impl<'de: 'a, 'a, Context, U> BorrowDecode<'de, Context> for Cow<'a, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        <&[u8]>::borrow_decode(decoder).map(Cow::borrowed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bincode::config::standard;
+
+    #[test]
+    fn wide_cow_str() {
+        use crate::Cow;
+
+        let expected = bincode::encode_to_vec("Hello", standard()).unwrap();
+        let owned: Cow<str> = Cow::owned(String::from("Hello"));
+
+        assert_eq!(
+            bincode::encode_to_vec(&owned, standard()).unwrap(),
+            expected
+        );
+
+        let (cow, _): (Cow<str>, _) =
+            bincode::borrow_decode_from_slice(&expected, standard()).unwrap();
+
+        assert_eq!(cow, "Hello");
+        assert!(cow.is_borrowed());
+
+        let (cow, _): (Cow<str>, _) = bincode::decode_from_slice(&expected, standard()).unwrap();
+
+        assert_eq!(cow, "Hello");
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn lean_cow_bytes() {
+        use crate::lean::Cow;
+
+        let expected = bincode::encode_to_vec(&b"Hello"[..], standard()).unwrap();
+        let owned: Cow<[u8]> = Cow::owned(b"Hello".to_vec());
+
+        assert_eq!(
+            bincode::encode_to_vec(&owned, standard()).unwrap(),
+            expected
+        );
+
+        let (cow, _): (Cow<[u8]>, _) =
+            bincode::borrow_decode_from_slice(&expected, standard()).unwrap();
+
+        assert_eq!(cow, &b"Hello"[..]);
+        assert!(cow.is_borrowed());
+
+        let (cow, _): (Cow<[u8]>, _) = bincode::decode_from_slice(&expected, standard()).unwrap();
+
+        assert_eq!(cow, &b"Hello"[..]);
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        use crate::Cow;
+
+        let bytes = bincode::encode_to_vec(&b"\xFF"[..], standard()).unwrap();
+
+        assert!(bincode::borrow_decode_from_slice::<Cow<str>, _>(&bytes, standard()).is_err());
+        assert!(bincode::decode_from_slice::<Cow<str>, _>(&bytes, standard()).is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::generic::Cow;
+use crate::traits::{Beef, Capacity};
+
+impl<T, U> BorshSerialize for Cow<'_, T, U>
+where
+    T: Beef + BorshSerialize + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        T::serialize(self.as_ref(), writer)
+    }
+}
+
+impl<T, U> BorshDeserialize for Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    T::Owned: BorshDeserialize,
+    U: Capacity,
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        T::Owned::deserialize_reader(reader).map(Cow::owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn wide_cow_str() {
+        use crate::Cow;
+
+        let borrowed: Cow<str> = Cow::borrowed("Hello");
+        let owned: Cow<str> = Cow::owned(String::from("Hello"));
+        let expected = borsh::to_vec(&String::from("Hello")).unwrap();
+
+        assert_eq!(borsh::to_vec(&borrowed).unwrap(), expected);
+        assert_eq!(borsh::to_vec(&owned).unwrap(), expected);
+
+        let cow: Cow<str> = borsh::from_slice(&expected).unwrap();
+
+        assert_eq!(cow, "Hello");
+        assert!(cow.is_owned());
+
+        assert!(borsh::from_slice::<Cow<str>>(&[1, 0, 0, 0, 0xFF]).is_err());
+    }
+
+    #[test]
+    fn lean_cow_slice() {
+        use crate::lean::Cow;
+
+        let data = vec![1u32, 2, 3];
+        let borrowed: Cow<[u32]> = Cow::borrowed(&data);
+        let owned: Cow<[u32]> = Cow::owned(data.clone());
+        let expected = borsh::to_vec(&data).unwrap();
+
+        assert_eq!(borsh::to_vec(&borrowed).unwrap(), expected);
+        assert_eq!(borsh::to_vec(&owned).unwrap(), expected);
+
+        let cow: Cow<[u32]> = borsh::from_slice(&expected).unwrap();
+
+        assert_eq!(cow, data);
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn nested() {
+        use crate::Cow;
+
+        let data = vec![String::from("foo"), String::from("bar")];
+        let cows: Cow<[Cow<str>]> = Cow::owned(data.iter().map(|s| Cow::borrowed(&**s)).collect());
+        let expected = borsh::to_vec(&data).unwrap();
+
+        assert_eq!(borsh::to_vec(&cows).unwrap(), expected);
+        assert_eq!(
+            borsh::from_slice::<Cow<[Cow<str>]>>(&expected).unwrap(),
+            cows
+        );
+    }
+}
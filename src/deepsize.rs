@@ -0,0 +1,69 @@
+use deepsize::{Context, DeepSizeOf};
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+/// Counts the capacity of an owned `String`. Borrowed strings don't own
+/// any memory and count as `0`.
+impl<U> DeepSizeOf for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn deep_size_of_children(&self, _: &mut Context) -> usize {
+        self.heap_size()
+    }
+}
+
+/// Counts the capacity of an owned `Vec`, plus whatever its elements
+/// own. Borrowed elements are not visited at all.
+impl<T, U> DeepSizeOf for Cow<'_, [T], U>
+where
+    T: Clone + DeepSizeOf,
+    U: Capacity,
+{
+    fn deep_size_of_children(&self, context: &mut Context) -> usize {
+        if self.is_borrowed() {
+            return 0;
+        }
+
+        self.iter()
+            .map(|child| child.deep_size_of_children(context))
+            .sum::<usize>()
+            + self.heap_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of;
+    use deepsize::DeepSizeOf;
+
+    #[test]
+    fn cow_str() {
+        let borrowed: crate::Cow<str> = crate::Cow::borrowed("Hello");
+        let owned: crate::lean::Cow<str> =
+            crate::lean::Cow::owned(String::with_capacity(32) + "Hello");
+
+        assert_eq!(borrowed.deep_size_of(), size_of::<crate::Cow<str>>());
+        assert_eq!(
+            owned.deep_size_of(),
+            size_of::<crate::lean::Cow<str>>() + 32
+        );
+    }
+
+    #[test]
+    fn cow_slice() {
+        let strings = vec![String::from("Hello"), String::from("World")];
+        let expected = strings.deep_size_of() - size_of::<Vec<String>>();
+
+        let borrowed: crate::Cow<[String]> = crate::Cow::borrowed(&strings);
+        let owned: crate::Cow<[String]> = crate::Cow::owned(strings.clone());
+
+        assert_eq!(borrowed.deep_size_of(), size_of::<crate::Cow<[String]>>());
+        assert_eq!(
+            owned.deep_size_of(),
+            size_of::<crate::Cow<[String]>>() + expected
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! `#[repr(C)]` representation of string and byte `Cow`s, for sharing with C.
+//!
+//! The layout of `beef::Cow` itself is not guaranteed. Convert it into an
+//! [`FfiCow`](./struct.FfiCow.html) first, and hand C a pointer to that. The matching
+//! C definition is available in [`include/beef.h`](https://github.com/maciejhirsz/beef/blob/master/include/beef.h).
+//!
+//! ```rust
+//! use beef::ffi::FfiCow;
+//! use beef::lean::Cow;
+//!
+//! let cow: Cow<str> = Cow::owned(String::from("Hello"));
+//! let ffi = FfiCow::from(cow);
+//!
+//! assert_eq!(ffi.len(), 5);
+//! assert!(ffi.is_owned());
+//!
+//! let cow: Cow<str> = ffi.into();
+//!
+//! assert_eq!(cow, "Hello");
+//! ```
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ptr::NonNull;
+
+use crate::generic::{Cow, RawCow};
+use crate::traits::Capacity;
+
+/// `Cow<str>` or `Cow<[u8]>` with a guaranteed layout, matching `beef_cow` in `include/beef.h`:
+///
+/// + a pointer to the bytes,
+/// + a `usize` length in bytes,
+/// + a `usize` capacity, `0` if the data is borrowed.
+///
+/// Both `beef::Cow` and `beef::lean::Cow` can be converted into, and back from, an `FfiCow`
+/// without copying the data. C code should only ever read it through a pointer, owned data
+/// is released when the `FfiCow` is dropped on the Rust side.
+#[repr(C)]
+pub struct FfiCow<'a, T: ?Sized> {
+    ptr: NonNull<u8>,
+    len: usize,
+    capacity: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<T: ?Sized> FfiCow<'_, T> {
+    /// Returns a pointer to the bytes.
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns the length in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the length is `0`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the data is owned.
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        self.capacity != 0
+    }
+}
+
+macro_rules! impl_ffi {
+    ($ty:ty) => {
+        impl FfiCow<'_, $ty> {
+            #[inline]
+            fn into_raw_parts(self) -> RawCow<$ty> {
+                let ffi = ManuallyDrop::new(self);
+
+                RawCow {
+                    ptr: ffi.ptr,
+                    len: ffi.len,
+                    capacity: ffi.capacity,
+                }
+            }
+        }
+
+        impl<'a, U> From<Cow<'a, $ty, U>> for FfiCow<'a, $ty>
+        where
+            U: Capacity,
+        {
+            #[inline]
+            fn from(cow: Cow<'a, $ty, U>) -> Self {
+                let raw = cow.into_raw_parts();
+
+                FfiCow {
+                    ptr: raw.ptr,
+                    len: raw.len,
+                    capacity: raw.capacity,
+                    marker: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, U> From<FfiCow<'a, $ty>> for Cow<'a, $ty, U>
+        where
+            U: Capacity,
+        {
+            /// # Panics
+            ///
+            /// For `beef::lean::Cow`, if the capacity doesn't fit in 32 bits.
+            #[inline]
+            fn from(ffi: FfiCow<'a, $ty>) -> Self {
+                // Safety: parts were decomposed from a `Cow` of the same type and lifetime
+                unsafe { Cow::from_raw_parts(ffi.into_raw_parts()) }
+            }
+        }
+    };
+}
+
+impl_ffi!(str);
+impl_ffi!([u8]);
+
+impl<T: ?Sized> Drop for FfiCow<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.capacity != 0 {
+            // Safety: owned data of both `str` and `[u8]` is a `Vec<u8>`
+            drop(unsafe { Vec::from_raw_parts(self.ptr.as_ptr(), self.len, self.capacity) });
+        }
+    }
+}
+
+// Safety: `FfiCow` is either a `&T`, or owns a `String` or `Vec<u8>`
+unsafe impl<T: Sync + ?Sized> Sync for FfiCow<'_, T> {}
+unsafe impl<T: Sync + ?Sized> Send for FfiCow<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::FfiCow;
+
+    // Mirror of `beef_cow` in `include/beef.h`
+    #[repr(C)]
+    struct BeefCow {
+        ptr: *const u8,
+        len: usize,
+        capacity: usize,
+    }
+
+    #[test]
+    fn wide() {
+        use crate::Cow;
+
+        let text = "Hello World";
+        let borrowed = FfiCow::from(Cow::borrowed(text));
+        let owned = FfiCow::from(Cow::<str>::owned(String::with_capacity(32) + text));
+
+        let raw = unsafe { &*(&borrowed as *const FfiCow<str> as *const BeefCow) };
+
+        assert_eq!(raw.ptr, text.as_ptr());
+        assert_eq!(raw.len, 11);
+        assert_eq!(raw.capacity, 0);
+
+        let raw = unsafe { &*(&owned as *const FfiCow<str> as *const BeefCow) };
+
+        assert_eq!(raw.ptr, owned.as_ptr());
+        assert_eq!(raw.len, 11);
+        assert_eq!(raw.capacity, 32);
+
+        let borrowed: Cow<str> = borrowed.into();
+        let owned: Cow<str> = owned.into();
+
+        assert!(borrowed.is_borrowed());
+        assert!(owned.is_owned());
+        assert_eq!(borrowed, text);
+        assert_eq!(owned, text);
+        assert_eq!(owned.capacity(), 32);
+    }
+
+    #[test]
+    fn lean() {
+        use crate::lean::Cow;
+
+        let bytes: &[u8] = b"Hello World";
+        let borrowed = FfiCow::from(Cow::borrowed(bytes));
+        let owned = FfiCow::from(Cow::<[u8]>::owned(bytes.to_vec()));
+
+        let raw = unsafe { &*(&borrowed as *const FfiCow<[u8]> as *const BeefCow) };
+
+        assert_eq!(raw.ptr, bytes.as_ptr());
+        assert_eq!(raw.len, 11);
+        assert_eq!(raw.capacity, 0);
+
+        let raw = unsafe { &*(&owned as *const FfiCow<[u8]> as *const BeefCow) };
+
+        assert_eq!(raw.ptr, owned.as_ptr());
+        assert_eq!(raw.len, 11);
+        assert_eq!(raw.capacity, 11);
+
+        let borrowed: Cow<[u8]> = borrowed.into();
+        let owned: Cow<[u8]> = owned.into();
+
+        assert!(borrowed.is_borrowed());
+        assert!(owned.is_owned());
+        assert_eq!(borrowed, bytes);
+        assert_eq!(owned, bytes);
+    }
+
+    #[test]
+    fn drop_owned() {
+        let owned = FfiCow::from(crate::Cow::<str>::owned(String::from("Hello")));
+
+        assert_eq!(owned.len(), 5);
+        assert!(!owned.is_empty());
+        assert!(owned.is_owned());
+    }
+}
@@ -5,14 +5,16 @@ use alloc::borrow::{Borrow, Cow as StdCow};
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
+use core::convert::Infallible;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::mem::ManuallyDrop;
+use core::mem::{self, ManuallyDrop};
 use core::ptr::NonNull;
 
 #[cfg(target_pointer_width = "64")]
 use crate::lean::internal::Lean;
+use crate::traits::IntoStatic;
 pub use crate::traits::{Beef, Capacity};
 use crate::wide::internal::Wide;
 
@@ -35,6 +37,46 @@ pub struct Cow<'a, T: Beef + ?Sized + 'a, U: Capacity> {
     marker: PhantomData<&'a T>,
 }
 
+/// Raw parts of a [`Cow`](./struct.Cow.html), see [`Cow::into_raw_parts`](./struct.Cow.html#method.into_raw_parts)
+/// and [`Cow::from_raw_parts`](./struct.Cow.html#method.from_raw_parts).
+pub struct RawCow<T: Beef + ?Sized> {
+    /// Pointer to data
+    pub ptr: NonNull<T::PointerT>,
+
+    /// Length of the data, in elements of `T::PointerT`. For `CStr` this
+    /// includes the nul terminator, for `OsStr` and `Path` it's the length
+    /// of their encoded bytes.
+    pub len: usize,
+
+    /// Capacity of the owned allocation, `0` if the data is borrowed
+    pub capacity: usize,
+}
+
+impl<T> Clone for RawCow<T>
+where
+    T: Beef + ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RawCow<T> where T: Beef + ?Sized {}
+
+impl<T> fmt::Debug for RawCow<T>
+where
+    T: Beef + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RawCow")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
 impl<T, U> Cow<'_, T, U>
 where
     T: Beef + ?Sized,
@@ -95,18 +137,197 @@ where
     pub fn into_owned(self) -> T::Owned {
         let cow = ManuallyDrop::new(self);
 
-        match cow.capacity() {
+        match cow.maybe_capacity() {
             Some(capacity) => unsafe { T::owned_from_parts::<U>(cow.ptr, cow.fat, capacity) },
             None => unsafe { &*T::ref_from_parts::<U>(cow.ptr, cow.fat) }.to_owned(),
         }
     }
 
+    /// Converts into a `Cow` with a `'static` lifetime.
+    ///
+    /// Clones the data if it is borrowed, owned data is moved as is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let text = String::from("Hello");
+    /// let borrowed: Cow<str> = Cow::borrowed(&text);
+    /// let detached: Cow<'static, str> = borrowed.into_static();
+    ///
+    /// drop(text);
+    ///
+    /// assert_eq!(detached, "Hello");
+    /// assert!(detached.is_owned());
+    /// ```
+    #[inline]
+    pub fn into_static(self) -> Cow<'static, T, U>
+    where
+        T: 'static,
+    {
+        Cow::owned(self.into_owned())
+    }
+
+    /// Borrows the data as a new `Cow`, without cloning it.
+    ///
+    /// This is cheaper than `clone`, which has to allocate for owned data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// fn takes_cow(cow: Cow<str>) -> usize {
+    ///     cow.len()
+    /// }
+    ///
+    /// let owned: Cow<str> = Cow::owned(String::from("Owned"));
+    /// let view = owned.as_borrowed();
+    ///
+    /// assert!(view.is_borrowed());
+    /// assert_eq!(view.as_ptr(), owned.as_ptr());
+    /// assert_eq!(takes_cow(owned.as_borrowed()), 5);
+    /// ```
+    #[inline]
+    pub fn as_borrowed(&self) -> Cow<'_, T, U> {
+        self.reborrow()
+    }
+
+    /// Reborrows a `&'b Cow<'a, T>` as a borrowed `Cow<'b, T>`, pointing
+    /// at the same data.
+    ///
+    /// Handy for passing a `Cow` by value to a function more than once,
+    /// without moving it or cloning the allocation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// fn push<'a>(out: &mut Vec<Cow<'a, str>>, cow: Cow<'a, str>) {
+    ///     out.push(cow);
+    /// }
+    ///
+    /// let owned: Cow<str> = Cow::owned(String::from("Owned"));
+    /// let mut out = Vec::new();
+    ///
+    /// push(&mut out, owned.reborrow());
+    /// push(&mut out, owned.reborrow());
+    ///
+    /// assert!(out.iter().all(|cow| cow.is_borrowed()));
+    /// assert!(out.iter().all(|cow| cow.as_ptr() == owned.as_ptr()));
+    /// ```
+    #[inline]
+    pub fn reborrow(&self) -> Cow<'_, T, U> {
+        Cow::borrowed(self.borrow())
+    }
+
+    /// Maps the data to a sub-borrow of itself.
+    ///
+    /// Borrowed data stays borrowed with the original lifetime. Owned data
+    /// is cloned, unless the closure returned the entire value unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let borrowed: Cow<str> = Cow::borrowed("  Hello  ");
+    /// let owned: Cow<str> = Cow::owned(String::from("  World  "));
+    ///
+    /// let borrowed = borrowed.map(str::trim);
+    /// let owned = owned.map(str::trim);
+    ///
+    /// assert_eq!(borrowed, "Hello");
+    /// assert_eq!(owned, "World");
+    /// assert!(borrowed.is_borrowed());
+    /// assert!(owned.is_owned());
+    /// ```
+    #[inline]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: for<'x> FnOnce(&'x T) -> &'x T,
+    {
+        match self.try_map(|val| Ok::<_, Infallible>(f(val))) {
+            Ok(cow) => cow,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Fallible version of [`map`](#method.map).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// fn unquote(s: &str) -> Result<&str, &'static str> {
+    ///     s.strip_prefix('"')
+    ///         .and_then(|s| s.strip_suffix('"'))
+    ///         .ok_or("not quoted")
+    /// }
+    ///
+    /// let quoted: Cow<str> = Cow::borrowed("\"Hello\"");
+    /// let bare: Cow<str> = Cow::borrowed("Hello");
+    ///
+    /// assert_eq!(quoted.try_map(unquote).unwrap(), "Hello");
+    /// assert_eq!(bare.try_map(unquote), Err("not quoted"));
+    /// ```
+    #[inline]
+    pub fn try_map<F, E>(self, f: F) -> Result<Self, E>
+    where
+        F: for<'x> FnOnce(&'x T) -> Result<&'x T, E>,
+    {
+        if self.is_borrowed() {
+            let val = unsafe { &*T::ref_from_parts::<U>(self.ptr, self.fat) };
+
+            return f(val).map(Cow::borrowed);
+        }
+
+        let val = f(self.borrow())?;
+
+        if core::ptr::eq(val, self.borrow()) {
+            Ok(self)
+        } else {
+            Ok(Cow::owned(val.to_owned()))
+        }
+    }
+
+    /// Maps the owned data, reusing its allocation if the data is already owned.
+    ///
+    /// Borrowed data is cloned before being passed to the closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let owned: Cow<str> = Cow::owned(String::with_capacity(32));
+    /// let ptr = owned.as_ptr();
+    ///
+    /// let owned = owned.map_owned(|mut s| {
+    ///     s.push_str("Hello");
+    ///     s
+    /// });
+    ///
+    /// assert_eq!(owned, "Hello");
+    /// assert_eq!(owned.as_ptr(), ptr);
+    /// ```
+    #[inline]
+    pub fn map_owned<F>(self, f: F) -> Self
+    where
+        F: FnOnce(T::Owned) -> T::Owned,
+    {
+        Cow::owned(f(self.into_owned()))
+    }
+
     /// Extracts borrowed data.
     ///
     /// Panics: If the data is owned.
     #[inline]
     pub fn unwrap_borrowed(self) -> &'a T {
-        if self.capacity().is_some() {
+        if self.maybe_capacity().is_some() {
             panic!("Can not turn owned beef::Cow into a borrowed value")
         }
         unsafe { &*T::ref_from_parts::<U>(self.ptr, self.fat) }
@@ -129,7 +350,7 @@ where
     /// ```
     #[inline]
     pub fn is_borrowed(&self) -> bool {
-        self.capacity().is_none()
+        self.maybe_capacity().is_none()
     }
 
     /// Returns `true` if data is owned and has non-0 capacity.
@@ -149,7 +370,132 @@ where
     /// ```
     #[inline]
     pub fn is_owned(&self) -> bool {
-        self.capacity().is_some()
+        self.maybe_capacity().is_some()
+    }
+
+    /// Returns the capacity of the owned allocation, or `0` if data is borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let borrowed: Cow<str> = Cow::borrowed("Borrowed");
+    /// let owned: Cow<str> = Cow::owned(String::with_capacity(10) + "Owned");
+    ///
+    /// assert_eq!(borrowed.capacity(), 0);
+    /// assert_eq!(owned.capacity(), 10);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match self.maybe_capacity() {
+            Some(capacity) => U::unpack(self.fat, capacity).1,
+            None => 0,
+        }
+    }
+
+    /// Returns the number of bytes allocated on the heap by this `Cow`,
+    /// or `0` if data is borrowed.
+    ///
+    /// This only accounts for the allocation holding `T`, not for any memory
+    /// owned by its elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let borrowed: Cow<[u32]> = Cow::borrowed(&[1, 2, 3]);
+    /// let owned: Cow<[u32]> = Cow::owned(Vec::with_capacity(10));
+    ///
+    /// assert_eq!(borrowed.heap_size(), 0);
+    /// assert_eq!(owned.heap_size(), 40);
+    /// ```
+    #[inline]
+    pub fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T::PointerT>()
+    }
+
+    /// Decomposes the `Cow` into its raw parts. The capacity of the returned
+    /// [`RawCow`](./struct.RawCow.html) is `0` for borrowed data.
+    ///
+    /// After calling this function the caller is responsible for the memory
+    /// previously managed by the `Cow`. The only way to release it is to convert
+    /// the parts back into a `Cow` with [`from_raw_parts`](#method.from_raw_parts).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let owned: Cow<str> = Cow::owned(String::with_capacity(10) + "Hello");
+    /// let raw = owned.into_raw_parts();
+    ///
+    /// assert_eq!(raw.len, 5);
+    /// assert_eq!(raw.capacity, 10);
+    ///
+    /// let owned: Cow<str> = unsafe { Cow::from_raw_parts(raw) };
+    ///
+    /// assert_eq!(owned, "Hello");
+    /// ```
+    #[inline]
+    pub fn into_raw_parts(self) -> RawCow<T> {
+        let cow = ManuallyDrop::new(self);
+        let (len, capacity) = match cow.maybe_capacity() {
+            Some(capacity) => U::unpack(cow.fat, capacity),
+            None => (U::len(cow.fat), 0),
+        };
+
+        RawCow {
+            ptr: cow.ptr,
+            len,
+            capacity,
+        }
+    }
+
+    /// Rebuilds a `Cow` from its raw parts.
+    ///
+    /// Parts produced by [`into_raw_parts`](#method.into_raw_parts) can be used to build
+    /// either `beef::Cow` or `beef::lean::Cow`, regardless of which one produced them.
+    ///
+    /// # Panics
+    ///
+    /// For `beef::lean::Cow`, if `capacity` doesn't fit in 32 bits.
+    ///
+    /// # Safety
+    ///
+    /// If `capacity` is `0` the data is borrowed:
+    ///
+    /// + `ptr` and `len` must describe a valid `&'a T`, valid for the entire lifetime `'a`.
+    /// + For `beef::lean::Cow`, `len` must fit in 32 bits.
+    ///
+    /// If `capacity` is not `0` the data is owned, and the `Cow` takes over its ownership:
+    ///
+    /// + `ptr`, `len` and `capacity` must be valid arguments for
+    ///   [`Vec::from_raw_parts`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.from_raw_parts).
+    ///   This is always the case for parts decomposed from an owned `String` or `Vec<T>`.
+    /// + The memory must not be used or released by anything else afterwards.
+    ///
+    /// In both cases the bytes must also be valid for `T`:
+    ///
+    /// + For `str` they must be valid UTF-8.
+    /// + For `CStr` `len` includes the nul terminator. The bytes must end with
+    ///   the terminator and contain no other nul bytes.
+    /// + For `OsStr` and `Path` they must come from `as_encoded_bytes` or
+    ///   `into_encoded_bytes`, on the same platform and the same Rust version.
+    #[inline]
+    pub unsafe fn from_raw_parts(raw: RawCow<T>) -> Self {
+        let (fat, cap) = match raw.capacity {
+            0 => U::empty(raw.len),
+            capacity => U::store(raw.len, capacity),
+        };
+
+        Cow {
+            ptr: raw.ptr,
+            fat,
+            cap,
+            marker: PhantomData,
+        }
     }
 
     /// Internal convenience method for casting `ptr` into a `&T`
@@ -159,7 +505,7 @@ where
     }
 
     #[inline]
-    fn capacity(&self) -> Option<U::NonZero> {
+    fn maybe_capacity(&self) -> Option<U::NonZero> {
         U::maybe(self.fat, self.cap)
     }
 }
@@ -363,6 +709,19 @@ where
     }
 }
 
+impl<T, U> IntoStatic for Cow<'_, T, U>
+where
+    T: Beef + ?Sized + 'static,
+    U: Capacity + 'static,
+{
+    type Static = Cow<'static, T, U>;
+
+    #[inline]
+    fn into_static(self) -> Cow<'static, T, U> {
+        Cow::into_static(self)
+    }
+}
+
 impl<T, U> Drop for Cow<'_, T, U>
 where
     T: Beef + ?Sized,
@@ -370,7 +729,7 @@ where
 {
     #[inline]
     fn drop(&mut self) {
-        if let Some(capacity) = self.capacity() {
+        if let Some(capacity) = self.maybe_capacity() {
             unsafe { T::owned_from_parts::<U>(self.ptr, self.fat, capacity) };
         }
     }
@@ -383,7 +742,7 @@ where
 {
     #[inline]
     fn clone(&self) -> Self {
-        match self.capacity() {
+        match self.maybe_capacity() {
             Some(_) => Cow::owned(self.borrow().to_owned()),
             None => Cow { ..*self },
         }
@@ -448,7 +807,7 @@ where
     fn from(cow: Cow<'a, T, U>) -> Self {
         let cow = ManuallyDrop::new(cow);
 
-        match cow.capacity() {
+        match cow.maybe_capacity() {
             Some(capacity) => {
                 StdCow::Owned(unsafe { T::owned_from_parts::<U>(cow.ptr, cow.fat, capacity) })
             }
@@ -0,0 +1,58 @@
+use get_size::GetSize;
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+/// Heap size of an owned `Cow<str>` is its capacity in bytes, borrowed
+/// strings report `0`.
+impl<U> GetSize for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn get_heap_size(&self) -> usize {
+        self.heap_size()
+    }
+}
+
+/// Heap size of an owned `Cow<[T]>` is `capacity * size_of::<T>()`, plus
+/// the heap size of each element.
+impl<T, U> GetSize for Cow<'_, [T], U>
+where
+    T: Clone + GetSize,
+    U: Capacity,
+{
+    fn get_heap_size(&self) -> usize {
+        if self.is_borrowed() {
+            return 0;
+        }
+
+        self.iter().map(GetSize::get_heap_size).sum::<usize>() + self.heap_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use get_size::GetSize;
+
+    #[test]
+    fn cow_str() {
+        let borrowed: crate::Cow<str> = crate::Cow::borrowed("Hello");
+        let owned: crate::lean::Cow<str> =
+            crate::lean::Cow::owned(String::with_capacity(32) + "Hello");
+
+        assert_eq!(borrowed.get_heap_size(), 0);
+        assert_eq!(owned.get_heap_size(), 32);
+    }
+
+    #[test]
+    fn cow_slice() {
+        let strings = vec![String::from("Hello"), String::from("World")];
+
+        let borrowed: crate::Cow<[String]> = crate::Cow::borrowed(&strings);
+        let owned: crate::Cow<[String]> = crate::Cow::owned(strings.clone());
+
+        assert_eq!(borrowed.get_heap_size(), 0);
+        assert_eq!(owned.get_heap_size(), strings.get_heap_size());
+    }
+}
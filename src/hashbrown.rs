@@ -0,0 +1,144 @@
+//! [`hashbrown`](https://docs.rs/hashbrown/) support, enabled with the `impl_hashbrown` feature.
+//!
+//! Maps keyed by a beef `Cow` can be queried with any other `Cow` holding the same
+//! data: `beef::Cow`, `beef::lean::Cow` or `std::borrow::Cow`, on top of the plain
+//! references that work through `Borrow`.
+//!
+//! ```rust
+//! use beef::hashbrown::CowKeyMap;
+//! use beef::lean::Cow;
+//! use hashbrown::HashMap;
+//!
+//! let mut counts: HashMap<Cow<str>, usize> = HashMap::new();
+//!
+//! for word in "foo bar foo".split(' ') {
+//!     *counts.entry_borrowed(word).or_insert(0) += 1;
+//! }
+//!
+//! assert_eq!(counts.get(&beef::Cow::borrowed("foo")), Some(&2));
+//! assert_eq!(counts.get(&std::borrow::Cow::Borrowed("bar")), Some(&1));
+//! ```
+
+use alloc::borrow::Cow as StdCow;
+use core::hash::{BuildHasher, Hash};
+
+use hashbrown::hash_map::{Entry, HashMap};
+use hashbrown::Equivalent;
+
+use crate::generic::Cow;
+#[cfg(target_pointer_width = "64")]
+use crate::lean::internal::Lean;
+use crate::traits::{Beef, Capacity};
+#[cfg(target_pointer_width = "64")]
+use crate::wide::internal::Wide;
+
+// `beef::lean::Cow` is the same type as `beef::Cow` on other targets, where these
+// impls would overlap with the blanket impl of `Equivalent`.
+#[cfg(target_pointer_width = "64")]
+impl<T> Equivalent<Cow<'_, T, Lean>> for Cow<'_, T, Wide>
+where
+    T: Beef + Eq + ?Sized,
+{
+    #[inline]
+    fn equivalent(&self, key: &Cow<'_, T, Lean>) -> bool {
+        **self == **key
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> Equivalent<Cow<'_, T, Wide>> for Cow<'_, T, Lean>
+where
+    T: Beef + Eq + ?Sized,
+{
+    #[inline]
+    fn equivalent(&self, key: &Cow<'_, T, Wide>) -> bool {
+        **self == **key
+    }
+}
+
+impl<T, U> Equivalent<Cow<'_, T, U>> for StdCow<'_, T>
+where
+    T: Beef + Eq + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn equivalent(&self, key: &Cow<'_, T, U>) -> bool {
+        **self == **key
+    }
+}
+
+/// Extension trait for maps keyed by a beef `Cow`.
+pub trait CowKeyMap<'a, T, U, V, S>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    /// Gets the entry for `key`. If it's vacant, inserting into it stores
+    /// `Cow::borrowed(key)`, so a lookup never allocates.
+    fn entry_borrowed(&mut self, key: &'a T) -> Entry<'_, Cow<'a, T, U>, V, S>;
+}
+
+impl<'a, T, U, V, S> CowKeyMap<'a, T, U, V, S> for HashMap<Cow<'a, T, U>, V, S>
+where
+    T: Beef + Hash + Eq + ?Sized,
+    U: Capacity,
+    S: BuildHasher,
+{
+    #[inline]
+    fn entry_borrowed(&mut self, key: &'a T) -> Entry<'_, Cow<'a, T, U>, V, S> {
+        self.entry(Cow::borrowed(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CowKeyMap;
+    use hashbrown::{HashMap, HashSet};
+    use std::borrow::Cow as StdCow;
+
+    use crate::lean::Cow as LeanCow;
+    use crate::Cow;
+
+    #[test]
+    fn lookup_across_cows() {
+        let mut set: HashSet<LeanCow<str>> = HashSet::new();
+
+        set.insert(LeanCow::borrowed("foo"));
+        set.insert(LeanCow::owned(String::from("bar")));
+
+        assert!(set.contains("foo"));
+        assert!(set.contains(&Cow::borrowed("foo")));
+        assert!(set.contains(&Cow::<str>::owned(String::from("bar"))));
+        assert!(set.contains(&StdCow::Borrowed("bar")));
+        assert!(set.contains(&StdCow::<str>::Owned(String::from("foo"))));
+        assert!(!set.contains(&Cow::borrowed("baz")));
+        assert!(!set.contains(&StdCow::Borrowed("baz")));
+
+        let mut map: HashMap<Cow<[u8]>, u8> = HashMap::new();
+
+        map.insert(Cow::borrowed(b"foo"), 1);
+
+        assert_eq!(map.get(&LeanCow::borrowed(&b"foo"[..])), Some(&1));
+        assert_eq!(map.get(&StdCow::Borrowed(&b"foo"[..])), Some(&1));
+    }
+
+    #[test]
+    fn entry_borrowed() {
+        let source = String::from("foo bar foo");
+        let mut map: HashMap<Cow<str>, usize> = HashMap::new();
+
+        map.insert(Cow::owned(String::from("bar")), 10);
+
+        for word in source.split(' ') {
+            *map.entry_borrowed(word).or_insert(0) += 1;
+        }
+
+        let (foo, _) = map.get_key_value("foo").unwrap();
+
+        assert!(foo.is_borrowed());
+        assert_eq!(foo.as_ptr(), source.as_ptr());
+        assert!(map.get_key_value("bar").unwrap().0.is_owned());
+        assert_eq!(map["foo"], 2);
+        assert_eq!(map["bar"], 11);
+    }
+}
@@ -0,0 +1,240 @@
+//! String interner handing out beef `Cow`s.
+//!
+//! ```rust
+//! use beef::intern::Interner;
+//! use beef::Cow;
+//!
+//! let source = String::from("foo bar foo");
+//! let mut interner = Interner::new();
+//!
+//! let symbols: Vec<_> = source
+//!     .split(' ')
+//!     .map(|word| interner.intern(Cow::borrowed(word)))
+//!     .collect();
+//!
+//! let baz = interner.intern(Cow::owned(String::from("baz")));
+//!
+//! assert_eq!(symbols[0], symbols[2]);
+//! assert_ne!(symbols[0], symbols[1]);
+//! assert_eq!(interner.len(), 3);
+//! assert_eq!(interner.resolve(symbols[1]), "bar");
+//! assert_eq!(interner.resolve(baz), "baz");
+//! assert!(interner.get(symbols[0]).is_borrowed());
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::generic::Cow;
+use crate::lean::Cow as LeanCow;
+use crate::traits::Capacity;
+
+/// Handle to a string stored in an [`Interner`](./struct.Interner.html).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the index of the string in the interner it came from.
+    /// Strings are indexed in the order they were first interned.
+    #[inline]
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Symbol({})", self.0)
+    }
+}
+
+/// Deduplicates strings by content, storing each unique string once.
+///
+/// Borrowed strings are stored without copying, owned strings are moved into
+/// the interner along with their allocation. Either way the string data is never
+/// copied. Each unique string is stored as a `beef::lean::Cow`, with a `&str` key
+/// and its `Symbol` in a `BTreeMap` for lookups.
+#[derive(Default)]
+pub struct Interner<'a> {
+    /// Keys borrow either from `'a`, or from the heap allocations of owned
+    /// strings in `strings`. Those are never mutated, and moving a `Cow` doesn't
+    /// move its data. Fields drop in declaration order, so the map is gone
+    /// before any of the strings are, and the keys are never left dangling.
+    /// They must never be handed out with the `'a` lifetime.
+    symbols: BTreeMap<&'a str, Symbol>,
+
+    strings: Vec<LeanCow<'a, str>>,
+}
+
+impl<'a> Interner<'a> {
+    /// Creates an empty interner.
+    #[inline]
+    pub fn new() -> Self {
+        Interner {
+            symbols: BTreeMap::new(),
+            strings: Vec::new(),
+        }
+    }
+
+    /// Interns a string, returning its symbol.
+    ///
+    /// If an equal string has been interned before, its symbol is returned
+    /// and `string` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already holds `u32::MAX` strings, or if an owned string
+    /// has capacity that doesn't fit in 32 bits.
+    pub fn intern<U>(&mut self, string: Cow<'a, str, U>) -> Symbol
+    where
+        U: Capacity,
+    {
+        if let Some(&symbol) = self.symbols.get(&*string) {
+            return symbol;
+        }
+
+        let symbol = Symbol(u32::try_from(self.strings.len()).expect("Too many interned strings"));
+        let string = if string.is_borrowed() {
+            LeanCow::borrowed(string.unwrap_borrowed())
+        } else {
+            LeanCow::owned(string.into_owned())
+        };
+
+        // Safety: see the docs on the `symbols` field.
+        let key = unsafe { &*(&*string as *const str) };
+
+        self.strings.push(string);
+        self.symbols.insert(key, symbol);
+
+        symbol
+    }
+
+    /// Returns the symbol of a string, if it has been interned.
+    #[inline]
+    pub fn lookup(&self, string: &str) -> Option<Symbol> {
+        self.symbols.get(string).copied()
+    }
+
+    /// Returns the interned string.
+    ///
+    /// # Panics
+    ///
+    /// If the symbol comes from a different interner.
+    #[inline]
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.strings[symbol.index()]
+    }
+
+    /// Returns the interned string as it is stored. Strings interned as borrowed
+    /// keep their `'a` lifetime, so cloning them doesn't allocate.
+    ///
+    /// # Panics
+    ///
+    /// If the symbol comes from a different interner.
+    #[inline]
+    pub fn get(&self, symbol: Symbol) -> &LeanCow<'a, str> {
+        &self.strings[symbol.index()]
+    }
+
+    /// Returns the number of unique strings interned.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if no strings have been interned.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Iterates over all symbols and their strings, in the order they were interned.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        self.strings
+            .iter()
+            .enumerate()
+            .map(|(index, string)| (Symbol(index as u32), &**string))
+    }
+}
+
+impl fmt::Debug for Interner<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interner;
+    use crate::lean::Cow as LeanCow;
+    use crate::Cow;
+
+    #[test]
+    fn deduplicates() {
+        let mut interner = Interner::new();
+
+        let foo = interner.intern(Cow::borrowed("foo"));
+        let bar = interner.intern(LeanCow::owned(String::from("bar")));
+
+        assert_eq!(interner.intern(Cow::owned(String::from("foo"))), foo);
+        assert_eq!(interner.intern(LeanCow::borrowed("bar")), bar);
+        assert_eq!(interner.lookup("foo"), Some(foo));
+        assert_eq!(interner.lookup("baz"), None);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(foo.index(), 0);
+        assert_eq!(bar.index(), 1);
+        assert_eq!(
+            format!("{:?}", interner),
+            r#"{Symbol(0): "foo", Symbol(1): "bar"}"#
+        );
+    }
+
+    #[test]
+    fn keeps_borrows_and_allocations() {
+        let source = String::from("foo");
+        let owned = String::from("bar");
+        let owned_ptr = owned.as_ptr();
+        let mut interner = Interner::new();
+
+        let foo = interner.intern(Cow::borrowed(&source[..]));
+        let bar = interner.intern(Cow::owned(owned));
+
+        assert!(interner.get(foo).is_borrowed());
+        assert_eq!(interner.resolve(foo).as_ptr(), source.as_ptr());
+        assert!(interner.get(bar).is_owned());
+        assert_eq!(interner.resolve(bar).as_ptr(), owned_ptr);
+
+        let foo: LeanCow<str> = interner.get(foo).clone();
+
+        drop(interner);
+
+        assert_eq!(foo, "foo");
+        assert!(foo.is_borrowed());
+    }
+
+    #[test]
+    fn keys_survive_growth() {
+        let mut interner = Interner::new();
+        let symbols: Vec<_> = (0..1000)
+            .map(|n| interner.intern(Cow::owned(n.to_string())))
+            .collect();
+
+        for (n, &symbol) in symbols.iter().enumerate() {
+            let string = n.to_string();
+
+            assert_eq!(interner.lookup(&string), Some(symbol));
+            assert_eq!(interner.resolve(symbol), string);
+        }
+
+        assert_eq!(
+            interner
+                .iter()
+                .map(|(symbol, _)| symbol)
+                .collect::<Vec<_>>(),
+            symbols
+        );
+    }
+}
@@ -21,14 +21,15 @@
 //! + `beef::Cow` is 3 words wide: pointer, length, and capacity. It stores the ownership tag in capacity.
 //! + `beef::lean::Cow` is 2 words wide, storing length, capacity, and the ownership tag all in one word.
 //!
-//! Both versions are leaner than the `std::borrow::Cow`:
+//! Older compilers made `std::borrow::Cow` 4 words wide. Recent ones fit it in 3 words,
+//! the same as `beef::Cow`, so only the lean variant is smaller than the `std::borrow::Cow`:
 //!
 //! ```rust
 //! use std::mem::size_of;
 //!
 //! const WORD: usize = size_of::<usize>();
 //!
-//! assert_eq!(size_of::<std::borrow::Cow<str>>(), 4 * WORD);
+//! assert_eq!(size_of::<std::borrow::Cow<str>>(), 3 * WORD);
 //! assert_eq!(size_of::<beef::Cow<str>>(), 3 * WORD);
 //!
 //! // Lean variant is two words on 64-bit architecture
@@ -37,16 +38,48 @@
 //! ```
 #![cfg_attr(feature = "const_fn", feature(const_fn_trait_bound))]
 #![warn(missing_docs)]
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 extern crate alloc;
 
 mod traits;
 mod wide;
 
+#[cfg(feature = "impl_arbitrary")]
+mod arbitrary;
+#[cfg(feature = "impl_bincode")]
+mod bincode;
+#[cfg(feature = "impl_borsh")]
+mod borsh;
+#[cfg(feature = "impl_deepsize")]
+mod deepsize;
+#[cfg(feature = "impl_get_size")]
+mod get_size;
+#[cfg(feature = "impl_hashbrown")]
+pub mod hashbrown;
+#[cfg(feature = "impl_logos")]
+pub mod logos;
+#[cfg(feature = "impl_nom")]
+pub mod nom;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "impl_regex")]
+pub mod regex;
+#[cfg(feature = "impl_rkyv")]
+mod rkyv;
+#[cfg(feature = "impl_schemars")]
+mod schemars;
 #[cfg(feature = "impl_serde")]
-mod serde;
-
+pub mod serde;
+#[cfg(feature = "impl_stable_deref")]
+mod stable_deref;
+#[cfg(feature = "impl_yoke")]
+mod yoke;
+#[cfg(feature = "impl_zeroize")]
+mod zeroize;
+
+pub mod ffi;
 pub mod generic;
+pub mod intern;
 #[cfg(target_pointer_width = "64")]
 pub mod lean;
 
@@ -56,8 +89,12 @@ pub mod lean {
     pub use super::wide::Cow;
 }
 
+pub use traits::IntoStatic;
 pub use wide::Cow;
 
+#[cfg(feature = "derive")]
+pub use beef_derive::IntoStatic;
+
 #[rustfmt::skip]
 macro_rules! test { ($tmod:ident => $cow:path) => {
     #[cfg(test)]
@@ -92,6 +129,175 @@ macro_rules! test { ($tmod:ident => $cow:path) => {
             assert_eq!(owned.into_owned(), hello);
         }
 
+        #[test]
+        fn into_static() {
+            let hello = String::from("Hello World");
+            let borrowed: Cow<str> = Cow::borrowed(&hello);
+            let owned: Cow<str> = Cow::owned(hello.clone());
+
+            let borrowed: Cow<'static, str> = borrowed.into_static();
+            let owned: Cow<'static, str> = owned.into_static();
+
+            drop(hello);
+
+            assert_eq!(borrowed, "Hello World");
+            assert_eq!(owned, "Hello World");
+            assert!(borrowed.is_owned());
+        }
+
+        #[test]
+        fn as_borrowed() {
+            let owned: Cow<str> = Cow::owned(String::from("Hello World"));
+            let borrowed = owned.as_borrowed();
+
+            assert!(owned.is_owned());
+            assert!(borrowed.is_borrowed());
+            assert_eq!(borrowed, owned);
+            assert_eq!(borrowed.as_ptr(), owned.as_ptr());
+
+            let reborrowed = borrowed.reborrow();
+
+            assert!(reborrowed.is_borrowed());
+            assert_eq!(reborrowed.as_ptr(), owned.as_ptr());
+        }
+
+        #[test]
+        fn map() {
+            let text = "  Hello World  ";
+            let borrowed: Cow<str> = Cow::borrowed(text);
+            let owned: Cow<str> = Cow::owned(text.to_owned());
+
+            let borrowed = borrowed.map(str::trim);
+            let owned = owned.map(str::trim);
+
+            assert_eq!(borrowed, "Hello World");
+            assert_eq!(owned, "Hello World");
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+            assert_eq!(borrowed.as_ptr(), text[2..].as_ptr());
+
+            let owned: Cow<str> = Cow::owned(text.to_owned());
+            let ptr = owned.as_ptr();
+            let owned = owned.map(|s| s);
+
+            assert_eq!(owned.as_ptr(), ptr);
+        }
+
+        #[test]
+        fn try_map() {
+            let borrowed: Cow<[u8]> = Cow::borrowed(b"Hello");
+            let owned: Cow<[u8]> = Cow::owned(b"Hello".to_vec());
+
+            let borrowed = borrowed.try_map(|s| s.get(1..).ok_or(())).unwrap();
+            let owned = owned.try_map(|s| s.get(1..).ok_or(())).unwrap();
+
+            assert_eq!(borrowed, &b"ello"[..]);
+            assert_eq!(owned, &b"ello"[..]);
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+
+            assert_eq!(borrowed.try_map(|s| s.get(10..).ok_or(())), Err(()));
+        }
+
+        #[test]
+        fn map_owned() {
+            let borrowed: Cow<str> = Cow::borrowed("Hello");
+            let owned: Cow<str> = Cow::owned(String::with_capacity(32));
+            let ptr = owned.as_ptr();
+
+            let borrowed = borrowed.map_owned(|mut s| {
+                s.push_str(" World");
+                s
+            });
+            let owned = owned.map_owned(|mut s| {
+                s.push_str("Hello World");
+                s
+            });
+
+            assert_eq!(borrowed, "Hello World");
+            assert_eq!(owned, "Hello World");
+            assert_eq!(owned.as_ptr(), ptr);
+        }
+
+        #[test]
+        fn raw_parts() {
+            let text = "Hello World";
+            let borrowed: Cow<str> = Cow::borrowed(text);
+            let owned: Cow<str> = Cow::owned(String::with_capacity(32) + text);
+
+            let borrowed = borrowed.into_raw_parts();
+            let owned = owned.into_raw_parts();
+
+            assert_eq!(borrowed.ptr.as_ptr() as *const u8, text.as_ptr());
+            assert_eq!(borrowed.len, 11);
+            assert_eq!(borrowed.capacity, 0);
+            assert_eq!(owned.len, 11);
+            assert_eq!(owned.capacity, 32);
+
+            let borrowed: Cow<str> = unsafe { Cow::from_raw_parts(borrowed) };
+            let owned: crate::generic::Cow<str, crate::wide::internal::Wide> =
+                unsafe { crate::generic::Cow::from_raw_parts(owned) };
+
+            assert_eq!(borrowed, text);
+            assert_eq!(owned, text);
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+            assert_eq!(owned.into_owned().capacity(), 32);
+        }
+
+        #[test]
+        fn capacity_and_heap_size() {
+            let borrowed: Cow<str> = Cow::borrowed("Hello");
+            let owned: Cow<str> = Cow::owned(String::with_capacity(32) + "Hello");
+            let slice: Cow<[u64]> = Cow::owned(Vec::with_capacity(4));
+
+            assert_eq!(borrowed.capacity(), 0);
+            assert_eq!(borrowed.heap_size(), 0);
+            assert_eq!(owned.capacity(), 32);
+            assert_eq!(owned.heap_size(), 32);
+            assert_eq!(slice.capacity(), 4);
+            assert_eq!(slice.heap_size(), 32);
+            assert_eq!(owned.as_borrowed().heap_size(), 0);
+        }
+
+        #[test]
+        fn cstr() {
+            use std::ffi::{CStr, CString};
+
+            let borrowed: Cow<CStr> = Cow::borrowed(CStr::from_bytes_with_nul(b"Hello\0").unwrap());
+            let owned: Cow<CStr> = Cow::owned(CString::new("World").unwrap());
+
+            assert_eq!(&*borrowed, CStr::from_bytes_with_nul(b"Hello\0").unwrap());
+            assert_eq!(&*owned, CStr::from_bytes_with_nul(b"World\0").unwrap());
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+            assert_eq!(owned.clone().into_owned().as_bytes(), b"World");
+            assert_eq!(borrowed.into_owned().as_bytes_with_nul(), b"Hello\0");
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn os_str_and_path() {
+            use std::ffi::{OsStr, OsString};
+            use std::path::{Path, PathBuf};
+
+            let borrowed: Cow<OsStr> = Cow::borrowed(OsStr::new("Hello"));
+            let owned: Cow<OsStr> = Cow::owned(OsString::from("World"));
+
+            assert_eq!(&*borrowed, OsStr::new("Hello"));
+            assert_eq!(owned.clone().into_owned(), OsString::from("World"));
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+
+            let borrowed: Cow<Path> = Cow::borrowed(Path::new("foo/bar"));
+            let owned: Cow<Path> = Cow::owned(PathBuf::from("foo").join("baz"));
+
+            assert_eq!(borrowed.file_name(), Some(OsStr::new("bar")));
+            assert_eq!(owned.clone().into_owned(), Path::new("foo/baz"));
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+        }
+
         #[test]
         fn borrowed_slice() {
             let s: &[_] = &[1, 2, 42];
@@ -0,0 +1,181 @@
+//! [`logos`](https://docs.rs/logos/) callbacks, enabled with the `impl_logos` feature.
+//!
+//! ```rust
+//! use beef::Cow;
+//! use logos::Logos;
+//!
+//! #[derive(Logos, Debug, PartialEq)]
+//! #[logos(skip r"\s+")]
+//! enum Token<'s> {
+//!     #[regex("[a-z]+", beef::logos::slice)]
+//!     Ident(Cow<'s, str>),
+//!
+//!     #[regex(r#""([^"\\]|\\.)*""#, beef::logos::unescape)]
+//!     Str(Cow<'s, str>),
+//! }
+//!
+//! let mut lex = Token::lexer(r#"foo "bar" "\"baz\"""#);
+//!
+//! assert_eq!(lex.next(), Some(Ok(Token::Ident("foo".into()))));
+//! assert_eq!(lex.next(), Some(Ok(Token::Str("bar".into()))));
+//! assert_eq!(lex.next(), Some(Ok(Token::Str("\"baz\"".into()))));
+//! assert_eq!(lex.next(), None);
+//! ```
+
+use alloc::string::String;
+use core::char;
+
+use logos::{Lexer, Logos};
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+/// Borrows the slice of the current token.
+#[inline]
+pub fn slice<'s, T, U>(lex: &mut Lexer<'s, T>) -> Cow<'s, str, U>
+where
+    T: Logos<'s, Source = str>,
+    U: Capacity,
+{
+    Cow::borrowed(lex.slice())
+}
+
+/// Strips the quotes off a string literal token and unescapes it.
+///
+/// The literal is borrowed if it contains no escapes, and owned otherwise.
+/// Either `'` or `"` can be used for quotes. Supported escapes are `\n`, `\r`,
+/// `\t`, `\0`, `\\`, `\'`, `\"` and `\u{...}`. Returns `None`, failing the token,
+/// if the literal isn't quoted or contains an invalid escape.
+pub fn unescape<'s, T, U>(lex: &mut Lexer<'s, T>) -> Option<Cow<'s, str, U>>
+where
+    T: Logos<'s, Source = str>,
+    U: Capacity,
+{
+    let literal = lex.slice();
+    let quote = literal.chars().next().filter(|&c| c == '"' || c == '\'')?;
+
+    if literal.len() < 2 || !literal.ends_with(quote) {
+        return None;
+    }
+
+    unescape_str(&literal[1..literal.len() - 1])
+}
+
+fn unescape_str<U: Capacity>(literal: &str) -> Option<Cow<'_, str, U>> {
+    let first = match literal.find('\\') {
+        Some(first) => first,
+        None => return Some(Cow::borrowed(literal)),
+    };
+
+    let mut unescaped = String::with_capacity(literal.len());
+    let mut chars = literal[first..].chars();
+
+    unescaped.push_str(&literal[..first]);
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        let c = match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            '\\' => '\\',
+            '\'' => '\'',
+            '"' => '"',
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+
+                let mut code = 0u32;
+                let mut digits = 0;
+
+                loop {
+                    match chars.next()? {
+                        '}' if digits > 0 => break,
+                        c if digits < 6 => code = code * 16 + c.to_digit(16)?,
+                        _ => return None,
+                    }
+                    digits += 1;
+                }
+
+                char::from_u32(code)?
+            }
+            _ => return None,
+        };
+
+        unescaped.push(c);
+    }
+
+    Some(Cow::owned(unescaped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{slice, unescape};
+    use logos::Logos;
+
+    use crate::lean::Cow as LeanCow;
+    use crate::Cow;
+
+    #[derive(Logos, Debug, PartialEq)]
+    #[logos(skip r"\s+")]
+    enum Token<'s> {
+        #[regex("[a-z]+", slice)]
+        Ident(LeanCow<'s, str>),
+
+        #[regex(r#""([^"\\]|\\.)*""#, unescape)]
+        #[regex(r#"'([^'\\]|\\.)*'"#, unescape)]
+        Str(Cow<'s, str>),
+    }
+
+    fn tokens(source: &str) -> Vec<Result<Token<'_>, ()>> {
+        Token::lexer(source).collect()
+    }
+
+    #[test]
+    fn borrows_slices() {
+        let source = "foo 'bar'";
+        let tokens = tokens(source);
+
+        match &tokens[..] {
+            [Ok(Token::Ident(foo)), Ok(Token::Str(bar))] => {
+                assert_eq!(foo, "foo");
+                assert_eq!(bar, "bar");
+                assert!(foo.is_borrowed());
+                assert!(bar.is_borrowed());
+                assert_eq!(bar.as_ptr(), source[5..].as_ptr());
+            }
+            tokens => panic!("unexpected tokens: {:?}", tokens),
+        }
+    }
+
+    #[test]
+    fn owns_unescaped() {
+        let tokens = tokens(r#""a\tb\\c\"" 'it\'s' "\u{1F42E}\u{2764}\n""#);
+
+        match &tokens[..] {
+            [Ok(Token::Str(a)), Ok(Token::Str(b)), Ok(Token::Str(c))] => {
+                assert_eq!(a, "a\tb\\c\"");
+                assert_eq!(b, "it's");
+                assert_eq!(c, "\u{1F42E}\u{2764}\n");
+                assert!(a.is_owned());
+                assert!(b.is_owned());
+                assert!(c.is_owned());
+            }
+            tokens => panic!("unexpected tokens: {:?}", tokens),
+        }
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        assert_eq!(tokens(r#""\q""#), [Err(())]);
+        assert_eq!(tokens(r#""\u{}""#), [Err(())]);
+        assert_eq!(tokens(r#""\u{1234567}""#), [Err(())]);
+        assert_eq!(tokens(r#""\u{D800}""#), [Err(())]);
+    }
+}
@@ -0,0 +1,395 @@
+//! [`nom`](https://docs.rs/nom/) support, enabled with the `impl_nom` feature.
+//!
+//! [`Input`](./struct.Input.html) is a borrowed string implementing nom's input traits.
+//! Everything a parser cuts out of it keeps the lifetime of the original data, and
+//! converts into a borrowed `Cow` without copying.
+//!
+//! ```rust
+//! use beef::nom::Input;
+//! use beef::Cow;
+//! use nom::bytes::complete::tag;
+//! use nom::character::complete::{alpha1, space1};
+//! use nom::sequence::separated_pair;
+//! use nom::IResult;
+//!
+//! fn assignment(input: Input) -> IResult<Input, (Cow<str>, Cow<str>)> {
+//!     let (rest, (name, value)) = separated_pair(alpha1, tag(" = "), alpha1)(input)?;
+//!
+//!     Ok((rest, (name.into(), value.into())))
+//! }
+//!
+//! let source: Cow<str> = Cow::borrowed("foo = bar");
+//! let (_, (name, value)) = assignment(Input::from(&source)).unwrap();
+//!
+//! assert_eq!(name, "foo");
+//! assert_eq!(value, "bar");
+//! assert!(name.is_borrowed());
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+use core::ops::{Deref, Range, RangeFrom, RangeFull, RangeTo};
+use core::str::{CharIndices, Chars, FromStr};
+
+use nom::error::{ErrorKind, ParseError};
+use nom::{
+    AsBytes, Compare, CompareResult, Err, ExtendInto, FindSubstring, FindToken, IResult, InputIter,
+    InputLength, InputTake, InputTakeAtPosition, Needed, Offset, ParseTo, Slice,
+};
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+/// Borrowed string input for nom parsers, see the [module docs](./index.html).
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Input<'a>(&'a str);
+
+impl<'a> Input<'a> {
+    /// Creates a new input out of a string slice.
+    #[inline]
+    pub const fn new(input: &'a str) -> Self {
+        Input(input)
+    }
+
+    /// Returns the remaining input as a string slice.
+    #[inline]
+    pub const fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Splits the input at a byte index, returning `(suffix, prefix)` like nom does.
+    #[inline]
+    fn split(&self, index: usize) -> (Self, Self) {
+        let (prefix, suffix) = self.0.split_at(index);
+
+        (Input(suffix), Input(prefix))
+    }
+}
+
+impl<'a> From<&'a str> for Input<'a> {
+    #[inline]
+    fn from(input: &'a str) -> Self {
+        Input(input)
+    }
+}
+
+/// Borrows the data of the `Cow`, whether it is borrowed or owned.
+impl<'a, U> From<&'a Cow<'_, str, U>> for Input<'a>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: &'a Cow<'_, str, U>) -> Self {
+        Input(cow)
+    }
+}
+
+impl<'a, U> From<Input<'a>> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(input: Input<'a>) -> Self {
+        Cow::borrowed(input.0)
+    }
+}
+
+impl Deref for Input<'_> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl AsRef<str> for Input<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl fmt::Debug for Input<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.0, f)
+    }
+}
+
+impl fmt::Display for Input<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)
+    }
+}
+
+impl PartialEq<str> for Input<'_> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Input<'_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl InputLength for Input<'_> {
+    #[inline]
+    fn input_len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl InputTake for Input<'_> {
+    #[inline]
+    fn take(&self, count: usize) -> Self {
+        Input(&self.0[..count])
+    }
+
+    #[inline]
+    fn take_split(&self, count: usize) -> (Self, Self) {
+        self.split(count)
+    }
+}
+
+impl<'a> InputIter for Input<'a> {
+    type Item = char;
+    type Iter = CharIndices<'a>;
+    type IterElem = Chars<'a>;
+
+    #[inline]
+    fn iter_indices(&self) -> CharIndices<'a> {
+        self.0.char_indices()
+    }
+
+    #[inline]
+    fn iter_elements(&self) -> Chars<'a> {
+        self.0.chars()
+    }
+
+    #[inline]
+    fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(char) -> bool,
+    {
+        self.0.find(predicate)
+    }
+
+    #[inline]
+    fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+        self.0.slice_index(count)
+    }
+}
+
+impl InputTakeAtPosition for Input<'_> {
+    type Item = char;
+
+    fn split_at_position<P, E: ParseError<Self>>(&self, predicate: P) -> IResult<Self, Self, E>
+    where
+        P: Fn(char) -> bool,
+    {
+        match self.0.find(predicate) {
+            Some(index) => Ok(self.split(index)),
+            None => Err(Err::Incomplete(Needed::new(1))),
+        }
+    }
+
+    fn split_at_position1<P, E: ParseError<Self>>(
+        &self,
+        predicate: P,
+        e: ErrorKind,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(char) -> bool,
+    {
+        match self.0.find(predicate) {
+            Some(0) => Err(Err::Error(E::from_error_kind(*self, e))),
+            Some(index) => Ok(self.split(index)),
+            None => Err(Err::Incomplete(Needed::new(1))),
+        }
+    }
+
+    fn split_at_position_complete<P, E: ParseError<Self>>(
+        &self,
+        predicate: P,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(char) -> bool,
+    {
+        match self.0.find(predicate) {
+            Some(index) => Ok(self.split(index)),
+            None => Ok(self.split(self.0.len())),
+        }
+    }
+
+    fn split_at_position1_complete<P, E: ParseError<Self>>(
+        &self,
+        predicate: P,
+        e: ErrorKind,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(char) -> bool,
+    {
+        match self.0.find(predicate) {
+            Some(0) => Err(Err::Error(E::from_error_kind(*self, e))),
+            Some(index) => Ok(self.split(index)),
+            None if self.0.is_empty() => Err(Err::Error(E::from_error_kind(*self, e))),
+            None => Ok(self.split(self.0.len())),
+        }
+    }
+}
+
+impl<'b> Compare<&'b str> for Input<'_> {
+    #[inline]
+    fn compare(&self, t: &'b str) -> CompareResult {
+        self.0.compare(t)
+    }
+
+    #[inline]
+    fn compare_no_case(&self, t: &'b str) -> CompareResult {
+        self.0.compare_no_case(t)
+    }
+}
+
+impl<'b> FindSubstring<&'b str> for Input<'_> {
+    #[inline]
+    fn find_substring(&self, substr: &'b str) -> Option<usize> {
+        self.0.find_substring(substr)
+    }
+}
+
+impl FindToken<char> for Input<'_> {
+    #[inline]
+    fn find_token(&self, token: char) -> bool {
+        self.0.find_token(token)
+    }
+}
+
+macro_rules! impl_slice {
+    ($($range:ty),*) => {$(
+        impl Slice<$range> for Input<'_> {
+            #[inline]
+            fn slice(&self, range: $range) -> Self {
+                Input(&self.0[range])
+            }
+        }
+    )*};
+}
+
+impl_slice!(Range<usize>, RangeTo<usize>, RangeFrom<usize>, RangeFull);
+
+impl Offset for Input<'_> {
+    #[inline]
+    fn offset(&self, second: &Self) -> usize {
+        self.0.offset(second.0)
+    }
+}
+
+impl AsBytes for Input<'_> {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<R: FromStr> ParseTo<R> for Input<'_> {
+    #[inline]
+    fn parse_to(&self) -> Option<R> {
+        self.0.parse().ok()
+    }
+}
+
+impl ExtendInto for Input<'_> {
+    type Item = char;
+    type Extender = String;
+
+    #[inline]
+    fn new_builder(&self) -> String {
+        String::new()
+    }
+
+    #[inline]
+    fn extend_into(&self, acc: &mut String) {
+        acc.push_str(self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Input;
+    use nom::branch::alt;
+    use nom::bytes::complete::{escaped_transform, tag, take_until};
+    use nom::character::complete::{alphanumeric1, char, digit1, none_of, space0};
+    use nom::combinator::{map_res, recognize, value};
+    use nom::multi::separated_list0;
+    use nom::sequence::{delimited, pair};
+    use nom::IResult;
+
+    use crate::lean::Cow as LeanCow;
+    use crate::Cow;
+
+    fn ident(input: Input) -> IResult<Input, Cow<str>> {
+        let (rest, ident) = recognize(pair(alphanumeric1, take_until(")")))(input)?;
+
+        Ok((rest, ident.into()))
+    }
+
+    #[test]
+    fn tokens_borrow_from_source() {
+        let source = String::from("call(foo bar)");
+        let (rest, (name, arg)) = pair(
+            alphanumeric1::<_, nom::error::Error<_>>,
+            delimited(tag("("), ident, char(')')),
+        )(Input::new(&source))
+        .unwrap();
+
+        let name: LeanCow<str> = name.into();
+
+        assert_eq!(rest, "");
+        assert_eq!(name, "call");
+        assert_eq!(arg, "foo bar");
+        assert!(name.is_borrowed());
+        assert!(arg.is_borrowed());
+        assert_eq!(arg.as_ptr(), source[5..].as_ptr());
+    }
+
+    #[test]
+    fn parse_owned_cow() {
+        fn numbers(input: Input) -> IResult<Input, Vec<u32>> {
+            separated_list0(
+                pair(tag(","), space0),
+                map_res(digit1, |digits: Input| digits.parse::<u32>()),
+            )(input)
+        }
+
+        let source: Cow<str> = Cow::owned(String::from("1, 22,333"));
+        let (_, numbers) = numbers(Input::from(&source)).unwrap();
+
+        assert_eq!(numbers, [1, 22, 333]);
+    }
+
+    #[test]
+    fn escaped() {
+        fn string(input: Input) -> IResult<Input, String> {
+            delimited(
+                char('"'),
+                escaped_transform(
+                    none_of("\\\""),
+                    '\\',
+                    alt((value("\\", tag("\\")), value("\"", tag("\"")))),
+                ),
+                char('"'),
+            )(input)
+        }
+
+        let (rest, unescaped) = string(Input::new(r#""a\"b" tail"#)).unwrap();
+
+        assert_eq!(unescaped, "a\"b");
+        assert_eq!(rest, " tail");
+    }
+}
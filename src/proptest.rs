@@ -0,0 +1,155 @@
+//! [`proptest`](https://docs.rs/proptest/) strategies, enabled with the `proptest` feature.
+//!
+//! The strategies in this module take a strategy for the owned type and randomly produce
+//! either a borrowed or an owned `Cow` out of its values, so that property tests cover
+//! every combination of ownership states.
+//!
+//! ```rust
+//! use beef::Cow;
+//! use proptest::test_runner::TestRunner;
+//!
+//! let mut runner = TestRunner::default();
+//!
+//! runner
+//!     .run(&beef::proptest::cow_str(".*"), |cow: Cow<str>| {
+//!         let expected = cow.to_string();
+//!
+//!         assert_eq!(cow.into_owned(), expected);
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+//!
+//! Borrowed values reference leaked heap allocations, as that is the only way to produce
+//! a `'static` borrow out of generated data. A new allocation is leaked every time a
+//! borrowed value is produced, and that includes every shrinking step while proptest
+//! simplifies a failing case. This makes these strategies fine for tests, but not for
+//! anything else, and they can't run under Miri, which reports the leaks as errors.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::ops::RangeInclusive;
+
+use proptest::prelude::*;
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+/// Spare capacity given to owned values. Never `0`, as an empty owned value
+/// with no capacity would end up borrowed.
+const SPARE: RangeInclusive<usize> = 1..=16;
+
+/// Wraps a strategy for `String`s into one for `Cow<'static, str>`.
+///
+/// Each value is either borrowed from a leaked allocation, or owned
+/// with 1 to 16 bytes of spare capacity.
+pub fn cow_str<S, U>(strategy: S) -> impl Strategy<Value = Cow<'static, str, U>>
+where
+    S: Strategy<Value = String>,
+    U: Capacity,
+{
+    (strategy, any::<bool>(), SPARE).prop_map(|(string, borrowed, spare)| {
+        if borrowed {
+            Cow::borrowed(&*Box::leak(string.into_boxed_str()))
+        } else {
+            owned_str(string, spare)
+        }
+    })
+}
+
+fn owned_str<U: Capacity>(string: String, spare: usize) -> Cow<'static, str, U> {
+    let mut owned = String::with_capacity(string.len() + spare);
+
+    owned.push_str(&string);
+
+    Cow::owned(owned)
+}
+
+/// Wraps a strategy for `Vec`s into one for `Cow<'static, [T]>`.
+///
+/// Each value is either borrowed from a leaked allocation, or owned
+/// with 1 to 16 elements of spare capacity.
+pub fn cow_slice<S, T, U>(strategy: S) -> impl Strategy<Value = Cow<'static, [T], U>>
+where
+    S: Strategy<Value = Vec<T>>,
+    T: Clone + Debug + 'static,
+    U: Capacity,
+{
+    (strategy, any::<bool>(), SPARE).prop_map(|(vec, borrowed, spare)| {
+        if borrowed {
+            Cow::borrowed(&*Box::leak(vec.into_boxed_slice()))
+        } else {
+            owned_slice(vec, spare)
+        }
+    })
+}
+
+fn owned_slice<T: Clone, U: Capacity>(vec: Vec<T>, spare: usize) -> Cow<'static, [T], U> {
+    let mut owned = Vec::with_capacity(vec.len() + spare);
+
+    owned.extend(vec);
+
+    Cow::owned(owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cow_slice, cow_str, owned_slice, owned_str, SPARE};
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use crate::lean::Cow as LeanCow;
+    use crate::Cow;
+
+    proptest! {
+        #[test]
+        fn owned_branch(string in ".{0,4}", bytes in vec(any::<u8>(), 0..4), spare in SPARE) {
+            let string: Cow<str> = owned_str(string, spare);
+            let bytes: LeanCow<[u8]> = owned_slice(bytes, spare);
+
+            prop_assert!(string.is_owned());
+            prop_assert!(bytes.is_owned());
+        }
+
+        #[test]
+        fn str_into_owned(cow in cow_str(".*")) {
+            let cow: Cow<str> = cow;
+            let expected = String::from(&*cow);
+
+            prop_assert_eq!(cow.into_owned(), expected);
+        }
+
+        #[test]
+        fn str_clone_and_eq(a in cow_str(".{0,4}"), b in cow_str(".{0,4}")) {
+            let a: Cow<str> = a;
+            let b: LeanCow<str> = b;
+
+            prop_assert_eq!(a.clone(), a.as_ref());
+            prop_assert_eq!(b.clone(), b.as_ref());
+            prop_assert_eq!(a == b, a.as_ref() == b.as_ref());
+        }
+
+        #[test]
+        fn slice_into_owned(cow in cow_slice(vec(any::<u16>(), 0..32))) {
+            let cow: LeanCow<[u16]> = cow;
+            let expected = cow.to_vec();
+
+            prop_assert_eq!(cow.into_owned(), expected);
+        }
+
+        #[test]
+        fn slice_clone_and_eq(
+            a in cow_slice(vec(0u8..4, 0..4)),
+            b in cow_slice(vec(0u8..4, 0..4)),
+        ) {
+            let a: Cow<[u8]> = a;
+            let b: LeanCow<[u8]> = b;
+
+            prop_assert_eq!(a.clone(), a.as_ref());
+            prop_assert_eq!(b.clone(), b.as_ref());
+            prop_assert_eq!(a == b, a.as_ref() == b.as_ref());
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! [`regex`](https://docs.rs/regex/) support, enabled with the `impl_regex` feature.
+//!
+//! `Cow<str>` implements [`Replacer`](https://docs.rs/regex/latest/regex/trait.Replacer.html),
+//! and the [`RegexExt`](./trait.RegexExt.html) trait adds replacement methods that take
+//! and return beef `Cow`s.
+//!
+//! ```rust
+//! use beef::regex::RegexExt;
+//! use beef::Cow;
+//! use regex::Regex;
+//!
+//! let re = Regex::new(r"(?P<first>\w+)\s+(?P<last>\w+)").unwrap();
+//!
+//! let replaced = re.replace_all_cow(Cow::borrowed("Hirsz Maciej"), "$last $first");
+//! let untouched = re.replace_all_cow(Cow::borrowed("Maciej"), "$last $first");
+//!
+//! assert_eq!(replaced, "Maciej Hirsz");
+//! assert_eq!(untouched, "Maciej");
+//! assert!(untouched.is_borrowed());
+//! ```
+
+use alloc::borrow::Cow as StdCow;
+use alloc::string::String;
+
+use regex::{Captures, Regex, Replacer};
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+/// Extension trait for [`Regex`](https://docs.rs/regex/latest/regex/struct.Regex.html)
+/// replacing text held in a beef `Cow`.
+pub trait RegexExt {
+    /// Replaces all non-overlapping matches in `text` with the replacement provided,
+    /// see [`Regex::replace_all`](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace_all).
+    ///
+    /// If nothing matched, `text` is returned as it was, so borrowed text stays
+    /// borrowed and owned text keeps its allocation.
+    fn replace_all_cow<'a, U, R>(&self, text: Cow<'a, str, U>, rep: R) -> Cow<'a, str, U>
+    where
+        U: Capacity,
+        R: Replacer;
+}
+
+impl RegexExt for Regex {
+    fn replace_all_cow<'a, U, R>(&self, text: Cow<'a, str, U>, rep: R) -> Cow<'a, str, U>
+    where
+        U: Capacity,
+        R: Replacer,
+    {
+        let replaced = match self.replace_all(&text, rep) {
+            StdCow::Borrowed(_) => None,
+            StdCow::Owned(replaced) => Some(replaced),
+        };
+
+        match replaced {
+            Some(replaced) => Cow::owned(replaced),
+            None => text,
+        }
+    }
+}
+
+impl<U> Replacer for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut String) {
+        caps.expand(self, dst);
+    }
+
+    #[inline]
+    fn no_expansion(&mut self) -> Option<StdCow<'_, str>> {
+        no_expansion(self)
+    }
+}
+
+impl<U> Replacer for &Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut String) {
+        caps.expand(self, dst);
+    }
+
+    #[inline]
+    fn no_expansion(&mut self) -> Option<StdCow<'_, str>> {
+        no_expansion(self)
+    }
+}
+
+#[inline]
+fn no_expansion(replacement: &str) -> Option<StdCow<'_, str>> {
+    if replacement.contains('$') {
+        None
+    } else {
+        Some(StdCow::Borrowed(replacement))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RegexExt;
+    use regex::{Regex, Replacer};
+
+    use crate::lean::Cow as LeanCow;
+    use crate::Cow;
+
+    #[test]
+    fn no_match_keeps_text() {
+        let re = Regex::new("[0-9]+").unwrap();
+
+        let borrowed = re.replace_all_cow(Cow::borrowed("Hello"), "#");
+        let owned: LeanCow<str> = LeanCow::owned(String::from("World"));
+        let ptr = owned.as_ptr();
+        let owned = re.replace_all_cow(owned, "#");
+
+        assert_eq!(borrowed, "Hello");
+        assert_eq!(owned, "World");
+        assert!(borrowed.is_borrowed());
+        assert!(owned.is_owned());
+        assert_eq!(owned.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn replaces_matches() {
+        let re = Regex::new("[0-9]+").unwrap();
+
+        let borrowed = re.replace_all_cow(Cow::borrowed("a1b22c"), "#");
+        let owned = re.replace_all_cow(
+            LeanCow::owned(String::from("333")),
+            |_: &regex::Captures| "n",
+        );
+
+        assert_eq!(borrowed, "a#b#c");
+        assert_eq!(owned, "n");
+        assert!(borrowed.is_owned());
+    }
+
+    #[test]
+    fn cow_replacer() {
+        let re = Regex::new(r"(\w+)@(\w+)").unwrap();
+        let mut literal: Cow<str> = Cow::borrowed("<redacted>");
+        let expand: LeanCow<str> = LeanCow::owned(String::from("$2 at $1"));
+
+        assert!(literal.no_expansion().is_some());
+        assert_eq!(re.replace_all("foo@bar", &literal), "<redacted>");
+        assert_eq!(re.replace_all("foo@bar", &expand), "bar at foo");
+        assert_eq!(re.replace_all("foo@bar", expand), "bar at foo");
+    }
+}
@@ -0,0 +1,170 @@
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
+
+use rkyv::rancor::{Fallible, Source};
+use rkyv::ser::{Allocator, Writer};
+use rkyv::string::{ArchivedString, StringResolver};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, Place, Serialize, SerializeUnsized};
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+impl<U> Archive for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedString::resolve_from_str(self, resolver, out);
+    }
+}
+
+impl<S, U> Serialize<S> for Cow<'_, str, U>
+where
+    S: Fallible + ?Sized,
+    S::Error: Source,
+    str: SerializeUnsized<S>,
+    U: Capacity,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(self, serializer)
+    }
+}
+
+impl<'a, D, U> Deserialize<Cow<'a, str, U>, D> for ArchivedString
+where
+    D: Fallible + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Cow<'a, str, U>, D::Error> {
+        Ok(Cow::owned(self.as_str().to_owned()))
+    }
+}
+
+/// Borrows the archived string without copying it.
+impl<'a, U> From<&'a ArchivedString> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(archived: &'a ArchivedString) -> Self {
+        Cow::borrowed(archived.as_str())
+    }
+}
+
+impl<T, U> Archive for Cow<'_, [T], U>
+where
+    T: Archive + Clone,
+    U: Capacity,
+{
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self, resolver, out);
+    }
+}
+
+impl<S, T, U> Serialize<S> for Cow<'_, [T], U>
+where
+    S: Fallible + Allocator + Writer + ?Sized,
+    T: Serialize<S> + Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self, serializer)
+    }
+}
+
+impl<'a, D, T, U> Deserialize<Cow<'a, [T], U>, D> for ArchivedVec<T::Archived>
+where
+    D: Fallible + ?Sized,
+    T: Archive + Clone,
+    ArchivedVec<T::Archived>: Deserialize<Vec<T>, D>,
+    U: Capacity,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<Cow<'a, [T], U>, D::Error> {
+        Deserialize::<Vec<T>, D>::deserialize(self, deserializer).map(Cow::owned)
+    }
+}
+
+/// Borrows the archived slice without copying it. This is only possible
+/// for types that archive as themselves, such as bytes.
+impl<'a, T, U> From<&'a ArchivedVec<T>> for Cow<'a, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn from(archived: &'a ArchivedVec<T>) -> Self {
+        Cow::borrowed(archived.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::rancor::Error;
+    use rkyv::{Archive, Deserialize, Serialize};
+
+    #[derive(Archive, Serialize, Deserialize)]
+    struct Test<'a> {
+        foo: crate::Cow<'a, str>,
+        bar: crate::lean::Cow<'a, [u8]>,
+        baz: crate::Cow<'a, [u32]>,
+    }
+
+    #[test]
+    fn archive_roundtrip() {
+        let test = Test {
+            foo: "Hello".into(),
+            bar: b"World".to_vec().into(),
+            baz: (&[1, 2, 3][..]).into(),
+        };
+
+        let bytes = rkyv::to_bytes::<Error>(&test).unwrap();
+        let archived = unsafe { rkyv::access_unchecked::<ArchivedTest>(&bytes) };
+
+        assert_eq!(archived.foo, "Hello");
+        assert_eq!(archived.bar.as_slice(), b"World");
+        assert_eq!(archived.baz.as_slice(), [1, 2, 3]);
+
+        let test = rkyv::deserialize::<Test<'static>, Error>(archived).unwrap();
+
+        assert_eq!(test.foo, "Hello");
+        assert_eq!(test.bar, &b"World"[..]);
+        assert_eq!(test.baz, &[1, 2, 3][..]);
+        assert!(test.foo.is_owned());
+        assert!(test.bar.is_owned());
+        assert!(test.baz.is_owned());
+    }
+
+    #[test]
+    fn borrow_from_archive() {
+        let test = Test {
+            foo: "Hello".into(),
+            bar: b"World".to_vec().into(),
+            baz: Vec::new().into(),
+        };
+
+        let bytes = rkyv::to_bytes::<Error>(&test).unwrap();
+        let archived = unsafe { rkyv::access_unchecked::<ArchivedTest>(&bytes) };
+
+        let foo: crate::lean::Cow<str> = (&archived.foo).into();
+        let bar: crate::Cow<[u8]> = (&archived.bar).into();
+
+        assert_eq!(foo, "Hello");
+        assert_eq!(bar, &b"World"[..]);
+        assert!(foo.is_borrowed());
+        assert!(bar.is_borrowed());
+        assert_eq!(foo.as_ptr(), archived.foo.as_str().as_ptr());
+    }
+}
@@ -0,0 +1,87 @@
+use alloc::borrow::Cow as StdCow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+impl<U> JsonSchema for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn inline_schema() -> bool {
+        String::inline_schema()
+    }
+
+    #[inline]
+    fn schema_name() -> StdCow<'static, str> {
+        String::schema_name()
+    }
+
+    #[inline]
+    fn schema_id() -> StdCow<'static, str> {
+        String::schema_id()
+    }
+
+    #[inline]
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        String::json_schema(generator)
+    }
+}
+
+impl<T, U> JsonSchema for Cow<'_, [T], U>
+where
+    T: Clone + JsonSchema,
+    U: Capacity,
+{
+    #[inline]
+    fn inline_schema() -> bool {
+        Vec::<T>::inline_schema()
+    }
+
+    #[inline]
+    fn schema_name() -> StdCow<'static, str> {
+        Vec::<T>::schema_name()
+    }
+
+    #[inline]
+    fn schema_id() -> StdCow<'static, str> {
+        Vec::<T>::schema_id()
+    }
+
+    #[inline]
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        Vec::<T>::json_schema(generator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::{schema_for, JsonSchema};
+
+    #[test]
+    fn cow_str() {
+        assert_eq!(schema_for!(crate::Cow<str>), schema_for!(String));
+        assert_eq!(schema_for!(crate::lean::Cow<str>), schema_for!(String));
+        assert_eq!(
+            <crate::Cow<str>>::schema_id(),
+            <String as JsonSchema>::schema_id()
+        );
+    }
+
+    #[test]
+    fn cow_slice() {
+        assert_eq!(schema_for!(crate::Cow<[u32]>), schema_for!(Vec<u32>));
+        assert_eq!(
+            schema_for!(crate::lean::Cow<[crate::Cow<str>]>),
+            schema_for!(Vec<String>)
+        );
+        assert_eq!(
+            <crate::lean::Cow<[bool]>>::schema_id(),
+            <Vec<bool> as JsonSchema>::schema_id()
+        );
+    }
+}
@@ -1,11 +1,17 @@
-use alloc::{borrow::ToOwned, string::String};
-use core::{fmt, marker::PhantomData};
+//! Serde support, enabled with the `impl_serde` feature.
+//!
+//! `Cow<str>`, `Cow<[T]>` and `Cow<CStr>` implement `Serialize` and `Deserialize` directly,
+//! as do `Cow<OsStr>` and `Cow<Path>` with the `std` feature enabled. This module
+//! contains helpers for use with `#[serde(with = "...")]` attributes.
 
-use serde::de::{self, Deserialize, Deserializer, Visitor};
+use alloc::{borrow::ToOwned, ffi::CString, string::String, vec::Vec};
+use core::{ffi::CStr, fmt, marker::PhantomData, str};
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
 use serde::ser::{Serialize, Serializer};
 
 use crate::generic::Cow;
-use crate::traits::internal::{Beef, Capacity};
+use crate::traits::{Beef, Capacity};
 
 impl<T, U> Serialize for Cow<'_, T, U>
 where
@@ -20,9 +26,9 @@ where
     }
 }
 
-struct CowVisitor<'de, 'a, T: Beef + ?Sized, U: Capacity>(
-    PhantomData<fn() -> (&'de T, Cow<'a, T, U>)>,
-);
+type VisitorMarker<'de, 'a, T, U> = PhantomData<fn() -> (&'de T, Cow<'a, T, U>)>;
+
+struct CowVisitor<'de, 'a, T: Beef + ?Sized, U: Capacity>(VisitorMarker<'de, 'a, T, U>);
 
 impl<'de, 'a, U> Visitor<'de> for CowVisitor<'de, 'a, str, U>
 where
@@ -55,6 +61,39 @@ where
     {
         Ok(Cow::owned(value))
     }
+
+    fn visit_borrowed_bytes<E>(self, value: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match str::from_utf8(value) {
+            Ok(s) => Ok(Cow::borrowed(s)),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(value), &self)),
+        }
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match str::from_utf8(value) {
+            Ok(s) => Ok(Cow::owned(s.to_owned())),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(value), &self)),
+        }
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match String::from_utf8(value) {
+            Ok(s) => Ok(Cow::owned(s)),
+            Err(err) => Err(E::invalid_value(
+                Unexpected::Bytes(&err.into_bytes()),
+                &self,
+            )),
+        }
+    }
 }
 
 impl<'de, 'a, U> Deserialize<'de> for Cow<'a, str, U>
@@ -86,6 +125,433 @@ where
     }
 }
 
+impl<'de, 'a, U> Visitor<'de> for CowVisitor<'de, 'a, CStr, U>
+where
+    'de: 'a,
+    U: Capacity,
+{
+    type Value = Cow<'a, CStr, U>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a C string")
+    }
+
+    fn visit_borrowed_bytes<E>(self, value: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // Input can only be borrowed if it includes the nul terminator
+        match CStr::from_bytes_with_nul(value) {
+            Ok(s) => Ok(Cow::borrowed(s)),
+            Err(_) => self.visit_byte_buf(value.to_vec()),
+        }
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_byte_buf(value.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // Accept the same input as the borrowed path, with or without the nul terminator
+        if value.last() == Some(&0) {
+            CString::from_vec_with_nul(value)
+                .map(Cow::owned)
+                .map_err(E::custom)
+        } else {
+            CString::new(value).map(Cow::owned).map_err(E::custom)
+        }
+    }
+
+    fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_borrowed_bytes(value.as_bytes())
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(value.as_bytes())
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_byte_buf(value.into_bytes())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+
+        self.visit_byte_buf(bytes)
+    }
+}
+
+/// Borrows from the input only if the bytes include the nul terminator.
+///
+/// `CStr` serializes with [`to_bytes`](https://doc.rust-lang.org/core/ffi/struct.CStr.html#method.to_bytes),
+/// leaving the terminator out, so deserializing a serialized `Cow<CStr>` always
+/// produces owned data.
+impl<'de, 'a, U> Deserialize<'de> for Cow<'a, CStr, U>
+where
+    'de: 'a,
+    U: Capacity,
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(CowVisitor::<'de, 'a, CStr, U>(PhantomData))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, 'a, U> Visitor<'de> for CowVisitor<'de, 'a, std::path::Path, U>
+where
+    'de: 'a,
+    U: Capacity,
+{
+    type Value = Cow<'a, std::path::Path, U>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("path string")
+    }
+
+    fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Cow::borrowed(std::path::Path::new(value)))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Cow::owned(value.into()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Cow::owned(value.into()))
+    }
+
+    fn visit_borrowed_bytes<E>(self, value: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match str::from_utf8(value) {
+            Ok(s) => self.visit_borrowed_str(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(value), &self)),
+        }
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match str::from_utf8(value) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(value), &self)),
+        }
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match String::from_utf8(value) {
+            Ok(s) => self.visit_string(s),
+            Err(err) => Err(E::invalid_value(
+                Unexpected::Bytes(&err.into_bytes()),
+                &self,
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, 'a, U> Deserialize<'de> for Cow<'a, std::path::Path, U>
+where
+    'de: 'a,
+    U: Capacity,
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(CowVisitor::<'de, 'a, std::path::Path, U>(PhantomData))
+    }
+}
+
+// `OsStr` is serialized as a platform specific enum, so it can never be borrowed
+#[cfg(feature = "std")]
+impl<'de, 'a, U> Deserialize<'de> for Cow<'a, std::ffi::OsStr, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        std::ffi::OsString::deserialize(deserializer).map(Cow::owned)
+    }
+}
+
+/// Serialize and deserialize `Cow<[u8]>` as bytes, rather than a sequence.
+///
+/// When the format supports it, deserialization borrows the bytes from the input.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::Cow;
+/// use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Blob<'a> {
+///     #[serde(borrow, with = "beef::serde::bytes")]
+///     data: Cow<'a, [u8]>,
+/// }
+///
+/// let blob: Blob = serde_json::from_str(r#"{"data":"Hello"}"#).unwrap();
+///
+/// assert_eq!(blob.data, &b"Hello"[..]);
+/// assert!(blob.data.is_borrowed());
+/// ```
+pub mod bytes {
+    use alloc::vec::Vec;
+    use core::{fmt, marker::PhantomData};
+
+    use serde::de::{self, Deserializer, SeqAccess, Visitor};
+    use serde::ser::Serializer;
+
+    use super::VisitorMarker;
+    use crate::generic::Cow;
+    use crate::traits::Capacity;
+
+    /// Serialize `Cow<[u8]>` using `Serializer::serialize_bytes`.
+    pub fn serialize<S, U>(cow: &Cow<'_, [u8], U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        U: Capacity,
+    {
+        serializer.serialize_bytes(cow)
+    }
+
+    /// Deserialize `Cow<[u8]>` using `Deserializer::deserialize_bytes`,
+    /// borrowing from the input if possible.
+    pub fn deserialize<'de, 'a, D, U>(deserializer: D) -> Result<Cow<'a, [u8], U>, D::Error>
+    where
+        'de: 'a,
+        D: Deserializer<'de>,
+        U: Capacity,
+    {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    }
+
+    struct BytesVisitor<'de, 'a, U: Capacity>(VisitorMarker<'de, 'a, [u8], U>);
+
+    impl<'de, 'a, U> Visitor<'de> for BytesVisitor<'de, 'a, U>
+    where
+        'de: 'a,
+        U: Capacity,
+    {
+        type Value = Cow<'a, [u8], U>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("bytes")
+        }
+
+        fn visit_borrowed_bytes<E>(self, value: &'de [u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Cow::borrowed(value))
+        }
+
+        fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Cow::owned(value.to_vec()))
+        }
+
+        fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Cow::owned(value))
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+
+            Ok(Cow::owned(bytes))
+        }
+    }
+}
+
+/// Serialize `Cow` as usual, but always deserialize it as owned data.
+///
+/// Since the deserialized data doesn't borrow from the input, this allows `Cow<'static, T>`
+/// fields to be used with `DeserializeOwned` APIs such as `serde_json::from_reader`.
+/// Works for both `str` and `[T]`.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::Cow;
+/// use serde_derive::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "beef::serde::owned")]
+///     name: Cow<'static, str>,
+/// }
+///
+/// let config: Config = serde_json::from_reader(&br#"{"name":"beef"}"#[..]).unwrap();
+///
+/// assert_eq!(config.name, "beef");
+/// assert!(config.name.is_owned());
+/// ```
+pub mod owned {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::{Serialize, Serializer};
+
+    use crate::generic::Cow;
+    use crate::traits::{Beef, Capacity};
+
+    /// Serialize `Cow` the same way as `T` is serialized.
+    pub fn serialize<S, T, U>(cow: &Cow<'_, T, U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Beef + Serialize + ?Sized,
+        U: Capacity,
+    {
+        T::serialize(cow.as_ref(), serializer)
+    }
+
+    /// Deserialize `Cow` from `T::Owned`, without borrowing from the input.
+    pub fn deserialize<'de, 'a, D, T, U>(deserializer: D) -> Result<Cow<'a, T, U>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Beef + ?Sized,
+        T::Owned: Deserialize<'de>,
+        U: Capacity,
+    {
+        T::Owned::deserialize(deserializer).map(Cow::owned)
+    }
+}
+
+/// Wrapper around a `Cow<'static, T>` that always deserializes as owned data,
+/// implementing `DeserializeOwned`. See also [`owned`](./owned/index.html).
+///
+/// # Example
+///
+/// ```rust
+/// use beef::serde::StaticCow;
+///
+/// let cow: StaticCow<beef::lean::Cow<'static, str>> =
+///     serde_json::from_reader(&br#""beef""#[..]).unwrap();
+///
+/// assert_eq!(*cow, "beef");
+/// assert!(cow.is_owned());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StaticCow<C>(pub C);
+
+impl<C> StaticCow<C> {
+    /// Unwraps the inner `Cow`.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+impl<C> core::ops::Deref for StaticCow<C> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C> core::ops::DerefMut for StaticCow<C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.0
+    }
+}
+
+impl<T, U> From<Cow<'static, T, U>> for StaticCow<Cow<'static, T, U>>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'static, T, U>) -> Self {
+        StaticCow(cow)
+    }
+}
+
+impl<C> Serialize for StaticCow<C>
+where
+    C: Serialize,
+{
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T, U> Deserialize<'de> for StaticCow<Cow<'static, T, U>>
+where
+    T: Beef + ?Sized,
+    T::Owned: Deserialize<'de>,
+    U: Capacity,
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        owned::deserialize(deserializer).map(StaticCow)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_derive::{Deserialize, Serialize};
@@ -143,7 +609,242 @@ mod tests {
 
         assert_eq!(cow, &b"foo"[..]);
 
-        // We need to stay generic over `[T]`, so no specialization for byte slices
+        // We need to stay generic over `[T]`, so no specialization for byte slices,
+        // `crate::serde::bytes` has to be used to borrow them
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn wide_cow_bytes() {
+        use crate::Cow;
+
+        #[derive(Serialize, Deserialize)]
+        struct Test<'a> {
+            #[serde(borrow, with = "crate::serde::bytes")]
+            foo: Cow<'a, [u8]>,
+            #[serde(borrow, with = "crate::serde::bytes")]
+            bar: Cow<'a, [u8]>,
+        }
+
+        let json = r#"{"foo":"Hello","bar":[119,111,114,108,100]}"#;
+        let test: Test = serde_json::from_str(json).unwrap();
+
+        assert_eq!(test.foo, &b"Hello"[..]);
+        assert_eq!(test.bar, &b"world"[..]);
+
+        assert!(test.foo.is_borrowed());
+        assert!(test.bar.is_owned());
+
+        let out = serde_json::to_string(&test).unwrap();
+
+        assert_eq!(
+            out,
+            r#"{"foo":[72,101,108,108,111],"bar":[119,111,114,108,100]}"#
+        );
+    }
+
+    #[test]
+    fn lean_cow_bytes() {
+        use crate::lean::Cow;
+        use serde::de::value::{BorrowedBytesDeserializer, BytesDeserializer, Error};
+
+        let bytes = b"Hello";
+
+        let cow: Cow<[u8]> =
+            crate::serde::bytes::deserialize(BorrowedBytesDeserializer::<Error>::new(bytes))
+                .unwrap();
+
+        assert_eq!(cow, &bytes[..]);
+        assert!(cow.is_borrowed());
+
+        let cow: Cow<[u8]> =
+            crate::serde::bytes::deserialize(BytesDeserializer::<Error>::new(bytes)).unwrap();
+
+        assert_eq!(cow, &bytes[..]);
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn wide_cow_from_bytes() {
+        use crate::Cow;
+        use serde::de::value::{
+            BorrowedBytesDeserializer, BytesDeserializer, Error, StringDeserializer,
+        };
+        use serde::Deserialize;
+
+        let cow = Cow::<str>::deserialize(BorrowedBytesDeserializer::<Error>::new(b"foo")).unwrap();
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_borrowed());
+
+        let cow = Cow::<str>::deserialize(BytesDeserializer::<Error>::new(b"foo")).unwrap();
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_owned());
+
+        let cow = Cow::<str>::deserialize(StringDeserializer::<Error>::new("foo".into())).unwrap();
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_owned());
+
+        assert!(Cow::<str>::deserialize(BorrowedBytesDeserializer::<Error>::new(b"\xFF")).is_err());
+        assert!(Cow::<str>::deserialize(BytesDeserializer::<Error>::new(b"\xFF")).is_err());
+    }
+
+    #[test]
+    fn wide_cow_owned() {
+        use crate::Cow;
+        use serde::de::DeserializeOwned;
+
+        #[derive(Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "crate::serde::owned")]
+            foo: Cow<'static, str>,
+            #[serde(with = "crate::serde::owned")]
+            bar: Cow<'static, [u8]>,
+        }
+
+        fn from_reader<T: DeserializeOwned>(json: &str) -> T {
+            serde_json::from_reader(json.as_bytes()).unwrap()
+        }
+
+        let json = r#"{"foo":"Hello","bar":[1,2,3]}"#;
+        let test: Test = from_reader(json);
+
+        assert_eq!(test.foo, "Hello");
+        assert_eq!(test.bar, &[1, 2, 3][..]);
+        assert!(test.foo.is_owned());
+        assert!(test.bar.is_owned());
+
+        // Borrowing input still produces owned data
+        let test: Test = serde_json::from_str(json).unwrap();
+
+        assert!(test.foo.is_owned());
+        assert_eq!(serde_json::to_string(&test).unwrap(), json);
+    }
+
+    #[test]
+    fn lean_static_cow() {
+        use crate::lean::Cow;
+        use crate::serde::StaticCow;
+
+        let json = r#"["Hello","World"]"#;
+        let cows: Vec<StaticCow<Cow<'static, str>>> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(*cows[0], "Hello");
+        assert_eq!(*cows[1], "World");
+        assert!(cows.iter().all(|cow| cow.is_owned()));
+        assert_eq!(serde_json::to_string(&cows).unwrap(), json);
+
+        let cow: StaticCow<Cow<'static, [u8]>> = serde_json::from_str("[1,2,3]").unwrap();
+
+        assert_eq!(cow.into_inner(), &[1, 2, 3][..]);
+    }
+
+    #[test]
+    fn wide_cow_nested() {
+        use crate::Cow;
+
+        #[derive(Serialize, Deserialize)]
+        struct Test<'a> {
+            #[serde(borrow)]
+            items: Cow<'a, [Cow<'a, str>]>,
+        }
+
+        let json = r#"{"items":["Hello","\tWorld!"]}"#;
+        let test: Test = serde_json::from_str(json).unwrap();
+
+        assert_eq!(test.items.len(), 2);
+        assert_eq!(test.items[0], "Hello");
+        assert_eq!(test.items[1], "\tWorld!");
+
+        assert!(test.items.is_owned());
+        assert!(test.items[0].is_borrowed());
+        assert!(test.items[1].is_owned());
+
+        let out = serde_json::to_string(&test).unwrap();
+
+        assert_eq!(json, out);
+    }
+
+    #[test]
+    fn lean_cow_nested_direct() {
+        use crate::lean::Cow;
+
+        let json = r#"["foo","bar"]"#;
+        let cow: Cow<[Cow<str>]> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(cow, &[Cow::borrowed("foo"), Cow::borrowed("bar")][..]);
+        assert!(cow.iter().all(Cow::is_borrowed));
+    }
+
+    #[test]
+    fn wide_cow_cstr() {
+        use crate::Cow;
+        use core::ffi::CStr;
+        use serde::de::value::{BorrowedBytesDeserializer, BytesDeserializer, Error};
+        use serde::Deserialize;
+
+        let json = "[102,111,111]";
+        let cow: Cow<CStr> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(&*cow, CStr::from_bytes_with_nul(b"foo\0").unwrap());
+        assert!(cow.is_owned());
+        assert_eq!(serde_json::to_string(&cow).unwrap(), json);
+
+        let cow =
+            Cow::<CStr>::deserialize(BorrowedBytesDeserializer::<Error>::new(b"foo\0")).unwrap();
+
+        assert_eq!(&*cow, CStr::from_bytes_with_nul(b"foo\0").unwrap());
+        assert!(cow.is_borrowed());
+
+        let cow =
+            Cow::<CStr>::deserialize(BorrowedBytesDeserializer::<Error>::new(b"foo")).unwrap();
+
+        assert_eq!(&*cow, CStr::from_bytes_with_nul(b"foo\0").unwrap());
+        assert!(cow.is_owned());
+
+        let cow = Cow::<CStr>::deserialize(BytesDeserializer::<Error>::new(b"foo\0")).unwrap();
+
+        assert_eq!(&*cow, CStr::from_bytes_with_nul(b"foo\0").unwrap());
+        assert!(cow.is_owned());
+
+        let cow: Cow<CStr> = serde_json::from_str("[102,111,111,0]").unwrap();
+
+        assert_eq!(&*cow, CStr::from_bytes_with_nul(b"foo\0").unwrap());
+        assert!(cow.is_owned());
+
+        let cow: Cow<CStr> = serde_json::from_str(r#""foo\u0000""#).unwrap();
+
+        assert_eq!(&*cow, CStr::from_bytes_with_nul(b"foo\0").unwrap());
+        assert!(cow.is_owned());
+
+        assert!(serde_json::from_str::<Cow<CStr>>("[102,0,111]").is_err());
+        assert!(serde_json::from_str::<Cow<CStr>>("[102,0,111,0]").is_err());
+        assert!(
+            Cow::<CStr>::deserialize(BorrowedBytesDeserializer::<Error>::new(b"f\0o\0")).is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn lean_cow_path() {
+        use crate::lean::Cow;
+        use std::ffi::OsStr;
+        use std::path::Path;
+
+        let json = r#""foo/bar.rs""#;
+        let cow: Cow<Path> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(&*cow, Path::new("foo/bar.rs"));
+        assert!(cow.is_borrowed());
+        assert_eq!(serde_json::to_string(&cow).unwrap(), json);
+
+        let cow: Cow<OsStr> = Cow::borrowed(OsStr::new("foo"));
+        let json = serde_json::to_string(&cow).unwrap();
+        let cow: Cow<OsStr> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(&*cow, OsStr::new("foo"));
         assert!(cow.is_owned());
     }
 }
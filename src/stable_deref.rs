@@ -0,0 +1,57 @@
+use stable_deref_trait::StableDeref;
+
+use crate::generic::Cow;
+use crate::traits::{Beef, Capacity};
+
+/// Borrowed data lives outside of the `Cow`, and owned data lives on the heap,
+/// so moving the `Cow` never moves the data it dereferences to.
+///
+/// `CloneStableDeref` is deliberately not implemented: cloning an owned `Cow`
+/// copies the data into a new allocation, so the clone dereferences to a
+/// different address.
+unsafe impl<T, U> StableDeref for Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use stable_deref_trait::StableDeref;
+
+    fn address<T: StableDeref>(cow: &T) -> *const u8
+    where
+        T::Target: AsRef<[u8]>,
+    {
+        cow.as_ref().as_ptr()
+    }
+
+    fn moved<T>(value: T) -> Box<T> {
+        Box::new(value)
+    }
+
+    #[test]
+    fn deref_is_stable_across_moves() {
+        let text = String::from("Hello");
+        let borrowed: crate::Cow<str> = crate::Cow::borrowed(&text);
+        let owned: crate::lean::Cow<str> = crate::lean::Cow::owned(String::from("World"));
+
+        let borrowed_ptr = address(&borrowed);
+        let owned_ptr = address(&owned);
+
+        let borrowed = moved(borrowed);
+        let owned = moved(owned);
+
+        assert_eq!(address(&*borrowed), borrowed_ptr);
+        assert_eq!(address(&*owned), owned_ptr);
+        assert_eq!(borrowed_ptr, text.as_ptr());
+    }
+
+    #[test]
+    fn clone_of_owned_moves_data() {
+        let owned: crate::Cow<[u8]> = crate::Cow::owned(b"Hello".to_vec());
+
+        assert_ne!(owned.clone().as_ptr(), owned.as_ptr());
+    }
+}
@@ -1,21 +1,91 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+
 use crate::lean::internal::Lean;
 use crate::wide::internal::Wide;
 pub(crate) use internal::InternalBeef;
 pub(crate) use internal::InternalCapacity;
 
+/// Marker trait for unsized types that can be stored in a `Cow`, namely `str`, `[T]`
+/// and `CStr`. With the `std` feature enabled this also includes `OsStr` and `Path`.
 pub trait Beef: InternalBeef {}
 impl<T: Clone> Beef for [T] {}
 impl Beef for str {}
+impl Beef for CStr {}
+#[cfg(feature = "std")]
+impl Beef for std::ffi::OsStr {}
+#[cfg(feature = "std")]
+impl Beef for std::path::Path {}
 
+/// Marker trait for the capacity strategies used by [`beef::Cow`](../type.Cow.html)
+/// and [`beef::lean::Cow`](../lean/type.Cow.html).
 pub trait Capacity: InternalCapacity {}
 impl Capacity for Lean {}
 impl Capacity for Wide {}
 
+/// Conversion into a type that holds no borrowed data, replacing all of its
+/// lifetimes with `'static`.
+///
+/// This is implemented for [`Cow`](./generic/struct.Cow.html), as well as `Vec`,
+/// `Option` and `Box` of types that implement it. With the `derive` feature
+/// enabled it can be derived for structs and enums using `#[derive(IntoStatic)]`.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::{Cow, IntoStatic};
+///
+/// let text = String::from("Hello World");
+/// let words: Vec<Cow<str>> = text.split(' ').map(Cow::borrowed).collect();
+/// let words: Vec<Cow<'static, str>> = words.into_static();
+///
+/// drop(text);
+///
+/// assert_eq!(words, ["Hello", "World"]);
+/// ```
+pub trait IntoStatic {
+    /// Type with all lifetimes replaced by `'static`.
+    type Static: 'static;
+
+    /// Detaches `self` from any borrowed data, cloning it if necessary.
+    fn into_static(self) -> Self::Static;
+}
+
+impl<T: IntoStatic> IntoStatic for Option<T> {
+    type Static = Option<T::Static>;
+
+    #[inline]
+    fn into_static(self) -> Self::Static {
+        self.map(T::into_static)
+    }
+}
+
+impl<T: IntoStatic> IntoStatic for Vec<T> {
+    type Static = Vec<T::Static>;
+
+    #[inline]
+    fn into_static(self) -> Self::Static {
+        self.into_iter().map(T::into_static).collect()
+    }
+}
+
+impl<T: IntoStatic> IntoStatic for Box<T> {
+    type Static = Box<T::Static>;
+
+    #[inline]
+    fn into_static(self) -> Self::Static {
+        Box::new(T::into_static(*self))
+    }
+}
+
 pub(crate) mod internal {
     use crate::generic::Capacity;
     use alloc::borrow::ToOwned;
+    use alloc::ffi::CString;
     use alloc::string::String;
     use alloc::vec::Vec;
+    use core::ffi::CStr;
     use core::mem::ManuallyDrop;
     use core::ptr::{slice_from_raw_parts, NonNull};
 
@@ -42,6 +112,11 @@ pub(crate) mod internal {
     /// + `T::Owned` has a `capacity`, which is an extra word that is absent in `T`.
     /// + `T::Owned` with `capacity` of `0` does not allocate memory.
     /// + `T::Owned` can be reconstructed from `*mut T` borrowed out of it, plus capacity.
+    ///
+    /// # Safety
+    ///
+    /// Implementors must uphold all of the requirements above, as `Cow` will
+    /// rebuild and drop `T::Owned` from the raw parts they produce.
     pub unsafe trait InternalBeef: ToOwned {
         type PointerT;
 
@@ -183,4 +258,146 @@ pub(crate) mod internal {
             Vec::from_raw_parts(ptr.as_ptr(), len, cap)
         }
     }
+
+    unsafe impl InternalBeef for CStr {
+        type PointerT = u8;
+
+        #[inline]
+        fn ref_into_parts<U>(&self) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            // Length includes the nul terminator, so that the `CStr`
+            // can be rebuilt without scanning the bytes again.
+            let bytes = self.to_bytes_with_nul();
+            let (fat, cap) = U::empty(bytes.len());
+
+            // A note on soundness:
+            //
+            // We are casting *const T to *mut T, however for all borrowed values
+            // this raw pointer is only ever dereferenced back to &T.
+            (
+                unsafe { NonNull::new_unchecked(bytes.as_ptr() as *mut u8) },
+                fat,
+                cap,
+            )
+        }
+
+        #[inline]
+        unsafe fn ref_from_parts<U>(ptr: NonNull<u8>, fat: usize) -> *const CStr
+        where
+            U: Capacity,
+        {
+            CStr::from_bytes_with_nul_unchecked(&*slice_from_raw_parts(ptr.as_ptr(), U::len(fat)))
+        }
+
+        #[inline]
+        fn owned_into_parts<U>(owned: CString) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            let mut owned = ManuallyDrop::new(owned.into_bytes_with_nul());
+            let (fat, cap) = U::store(owned.len(), owned.capacity());
+
+            (
+                unsafe { NonNull::new_unchecked(owned.as_mut_ptr()) },
+                fat,
+                cap,
+            )
+        }
+
+        #[inline]
+        unsafe fn owned_from_parts<U>(ptr: NonNull<u8>, fat: usize, capacity: U::NonZero) -> CString
+        where
+            U: Capacity,
+        {
+            let (len, cap) = U::unpack(fat, capacity);
+
+            CString::from_vec_with_nul_unchecked(Vec::from_raw_parts(ptr.as_ptr(), len, cap))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    unsafe impl InternalBeef for std::ffi::OsStr {
+        type PointerT = u8;
+
+        #[inline]
+        fn ref_into_parts<U>(&self) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            <[u8]>::ref_into_parts::<U>(self.as_encoded_bytes())
+        }
+
+        #[inline]
+        unsafe fn ref_from_parts<U>(ptr: NonNull<u8>, fat: usize) -> *const std::ffi::OsStr
+        where
+            U: Capacity,
+        {
+            std::ffi::OsStr::from_encoded_bytes_unchecked(&*<[u8]>::ref_from_parts::<U>(ptr, fat))
+        }
+
+        #[inline]
+        fn owned_into_parts<U>(owned: std::ffi::OsString) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            <[u8]>::owned_into_parts::<U>(owned.into_encoded_bytes())
+        }
+
+        #[inline]
+        unsafe fn owned_from_parts<U>(
+            ptr: NonNull<u8>,
+            fat: usize,
+            capacity: U::NonZero,
+        ) -> std::ffi::OsString
+        where
+            U: Capacity,
+        {
+            std::ffi::OsString::from_encoded_bytes_unchecked(<[u8]>::owned_from_parts::<U>(
+                ptr, fat, capacity,
+            ))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    unsafe impl InternalBeef for std::path::Path {
+        type PointerT = u8;
+
+        #[inline]
+        fn ref_into_parts<U>(&self) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            self.as_os_str().ref_into_parts::<U>()
+        }
+
+        #[inline]
+        unsafe fn ref_from_parts<U>(ptr: NonNull<u8>, fat: usize) -> *const std::path::Path
+        where
+            U: Capacity,
+        {
+            std::path::Path::new(&*std::ffi::OsStr::ref_from_parts::<U>(ptr, fat))
+        }
+
+        #[inline]
+        fn owned_into_parts<U>(owned: std::path::PathBuf) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            std::ffi::OsStr::owned_into_parts::<U>(owned.into_os_string())
+        }
+
+        #[inline]
+        unsafe fn owned_from_parts<U>(
+            ptr: NonNull<u8>,
+            fat: usize,
+            capacity: U::NonZero,
+        ) -> std::path::PathBuf
+        where
+            U: Capacity,
+        {
+            std::ffi::OsStr::owned_from_parts::<U>(ptr, fat, capacity).into()
+        }
+    }
 }
@@ -0,0 +1,119 @@
+use core::mem::{self, ManuallyDrop};
+use core::ptr;
+
+use yoke::Yokeable;
+use zerofrom::ZeroFrom;
+
+use crate::generic::Cow;
+use crate::traits::{Beef, Capacity};
+
+// Safety: `Cow<'a, T, U>` is covariant in `'a`.
+unsafe impl<'a, T, U> Yokeable<'a> for Cow<'static, T, U>
+where
+    T: Beef + ?Sized + 'static,
+    U: Capacity + 'static,
+{
+    type Output = Cow<'a, T, U>;
+
+    #[inline]
+    fn transform(&'a self) -> &'a Cow<'a, T, U> {
+        self
+    }
+
+    #[inline]
+    fn transform_owned(self) -> Cow<'a, T, U> {
+        self
+    }
+
+    #[inline]
+    unsafe fn make(from: Cow<'a, T, U>) -> Self {
+        // The types only differ in lifetime, which `mem::transmute` can't see through.
+        let from = ManuallyDrop::new(from);
+        let ptr: *const Self = (&*from as *const Cow<'a, T, U>).cast();
+
+        ptr::read(ptr)
+    }
+
+    #[inline]
+    fn transform_mut<F>(&'a mut self, f: F)
+    where
+        F: 'static + for<'b> FnOnce(&'b mut Self::Output),
+    {
+        // Safety: the signature of `F` prevents it from leaking a shorter
+        // lifetime into `self`, see the docs of `Yokeable::transform_mut`.
+        unsafe { f(mem::transmute::<&'a mut Self, &'a mut Self::Output>(self)) }
+    }
+}
+
+/// Borrows the data of the other `Cow`, whether it is borrowed or owned.
+impl<'zf, T, U> ZeroFrom<'zf, Cow<'_, T, U>> for Cow<'zf, T, U>
+where
+    T: Beef + ?Sized + 'zf,
+    U: Capacity + 'zf,
+{
+    #[inline]
+    fn zero_from(other: &'zf Cow<'_, T, U>) -> Self {
+        other.as_borrowed()
+    }
+}
+
+impl<'zf, U> ZeroFrom<'zf, str> for Cow<'zf, str, U>
+where
+    U: Capacity + 'zf,
+{
+    #[inline]
+    fn zero_from(other: &'zf str) -> Self {
+        Cow::borrowed(other)
+    }
+}
+
+impl<'zf, T, U> ZeroFrom<'zf, [T]> for Cow<'zf, [T], U>
+where
+    T: Clone + 'zf,
+    U: Capacity + 'zf,
+{
+    #[inline]
+    fn zero_from(other: &'zf [T]) -> Self {
+        Cow::borrowed(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use yoke::Yoke;
+    use zerofrom::ZeroFrom;
+
+    #[test]
+    fn yoke_to_cart() {
+        let cart: Rc<[u8]> = Rc::from(&b"Hello World"[..]);
+        let yoke =
+            Yoke::<crate::Cow<'static, str>, Rc<[u8]>>::attach_to_cart(cart.clone(), |bytes| {
+                crate::Cow::borrowed(core::str::from_utf8(&bytes[6..]).unwrap())
+            });
+
+        assert_eq!(yoke.get(), "World");
+        assert!(yoke.get().is_borrowed());
+        assert_eq!(yoke.get().as_ptr(), cart[6..].as_ptr());
+    }
+
+    #[test]
+    fn zero_copy_cart() {
+        let yoke = Yoke::<crate::lean::Cow<'static, str>, String>::attach_to_zero_copy_cart(
+            String::from("Hello"),
+        );
+
+        assert_eq!(yoke.get(), "Hello");
+        assert!(yoke.get().is_borrowed());
+    }
+
+    #[test]
+    fn zero_from_cow() {
+        let owned: crate::Cow<[u8]> = crate::Cow::owned(b"Hello".to_vec());
+        let borrowed = crate::Cow::<[u8]>::zero_from(&owned);
+
+        assert!(borrowed.is_borrowed());
+        assert_eq!(borrowed.as_ptr(), owned.as_ptr());
+    }
+}
@@ -0,0 +1,113 @@
+use core::mem;
+
+use zeroize::Zeroize;
+
+use crate::generic::Cow;
+use crate::traits::Capacity;
+
+/// Wipes the entire owned allocation, including any spare capacity, leaving
+/// an empty `Cow` that still holds on to it. Borrowed data belongs to someone
+/// else and is left untouched.
+///
+/// Wrap the `Cow` in [`zeroize::Zeroizing`] to have it wiped on drop.
+impl<U> Zeroize for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    fn zeroize(&mut self) {
+        if self.is_owned() {
+            let mut owned = mem::replace(self, Cow::borrowed("")).into_owned();
+
+            owned.zeroize();
+
+            *self = Cow::owned(owned);
+        }
+    }
+}
+
+/// Zeroizes every element, then wipes the allocation the same way
+/// as the `str` impl.
+impl<T, U> Zeroize for Cow<'_, [T], U>
+where
+    T: Clone + Zeroize,
+    U: Capacity,
+{
+    fn zeroize(&mut self) {
+        if self.is_owned() {
+            let mut owned = mem::replace(self, Cow::borrowed(&[])).into_owned();
+
+            owned.zeroize();
+
+            *self = Cow::owned(owned);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::slice;
+    use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+    fn spare(secret: &str) -> String {
+        let mut string = String::with_capacity(32);
+
+        string.push_str(secret);
+        string.push_str("hunter2");
+        string.truncate(secret.len());
+        string
+    }
+
+    #[test]
+    fn wipes_capacity() {
+        let mut cow: crate::lean::Cow<str> = crate::lean::Cow::owned(spare("password"));
+
+        cow.zeroize();
+
+        assert_eq!(cow, "");
+        assert!(cow.is_owned());
+
+        let raw = cow.into_raw_parts();
+        let bytes = unsafe { slice::from_raw_parts(raw.ptr.as_ptr(), raw.capacity) };
+
+        assert_eq!(raw.capacity, 32);
+        assert!(bytes.iter().all(|&byte| byte == 0));
+
+        drop(unsafe { crate::lean::Cow::<str>::from_raw_parts(raw) });
+    }
+
+    #[test]
+    fn wipes_slice() {
+        let mut cow: crate::Cow<[u64]> = crate::Cow::owned(vec![1, 2, 3]);
+
+        cow.zeroize();
+
+        assert!(cow.is_empty());
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn leaves_borrowed() {
+        let secret = String::from("password");
+        let mut cow = crate::Cow::borrowed(&secret[..]);
+
+        cow.zeroize();
+
+        assert_eq!(cow, "password");
+        assert_eq!(cow.as_ptr(), secret.as_ptr());
+        assert!(cow.is_borrowed());
+    }
+
+    #[test]
+    fn zeroizing() {
+        fn zeroize_on_drop<Z: ZeroizeOnDrop>(_: &Z) {}
+
+        let mut cow = Zeroizing::new(crate::Cow::<str>::owned(spare("password")));
+
+        zeroize_on_drop(&cow);
+        assert_eq!(*cow, "password");
+
+        cow.zeroize();
+
+        assert_eq!(*cow, "");
+    }
+}